@@ -1,7 +1,7 @@
 //src/adminDashboard/adminDashboardController.ts
 
 import { PublicKey, SystemProgram } from '@solana/web3.js';
-import { checkPoolStatus, initializeStakingPoolService, initializeRewardPoolService, initializePrizePoolService, initializePlatformConfigService, updatePlatformConfigService, updatePlatformWalletService, transferSuperAdminService, getPlatformConfigService } from "./services";
+import { checkPoolStatus, initializeStakingPoolService, initializeRewardPoolService, linkRewardPoolService, initializePrizePoolService, initializePlatformConfigService, updatePlatformConfigService, proposePlatformWalletService, acceptPlatformWalletService, proposeSuperAdminService, acceptSuperAdminService, getPlatformConfigService } from "./services";
 import { getTournamentPoolPDA, getPrizePoolPDA, TokenType } from "../utils/getPDAs";
 import { getProgram } from "../staking/services";
 import { Request, Response } from 'express';
@@ -304,6 +304,48 @@ export const initializeRewardPoolController = async (req: Request, res: Response
     }
 };
 
+/**
+ * Controller function for linking a reward pool to a staking pool
+ */
+export const linkRewardPoolController = async (req: Request, res: Response) => {
+    try {
+        const { stakingMintPublicKey, stakingTokenType, rewardMintPublicKey, rewardTokenType, adminPublicKey } = req.body;
+
+        if (!stakingMintPublicKey || !rewardMintPublicKey || !adminPublicKey || stakingTokenType === undefined || stakingTokenType === null || rewardTokenType === undefined || rewardTokenType === null) {
+            return res.status(400).json({
+                success: false,
+                message: 'Staking mint, reward mint, both token types and admin public key are required'
+            });
+        }
+
+        const stt = Number(stakingTokenType);
+        const rtt = Number(rewardTokenType);
+        if ((stt !== TokenType.SPL && stt !== TokenType.SOL) || (rtt !== TokenType.SPL && rtt !== TokenType.SOL)) {
+            return res.status(400).json({ success: false, message: 'Token types must be 0 (SPL) or 1 (SOL)' });
+        }
+        const result = await linkRewardPoolService(
+            new PublicKey(stakingMintPublicKey),
+            stt as TokenType,
+            new PublicKey(rewardMintPublicKey),
+            rtt as TokenType,
+            new PublicKey(adminPublicKey)
+        );
+
+        if (result.success) {
+            return res.status(200).json({data: result});
+        } else {
+            return res.status(500).json({ error: result.message });
+        }
+    } catch (err) {
+        console.error('Error in link reward pool controller:', err);
+        return res.status(500).json({
+            success: false,
+            message: 'Failed to link reward pool',
+            error: err.message || err
+        });
+    }
+};
+
 
 
 
@@ -675,7 +717,8 @@ export const initializePlatformConfigController = async (req: Request, res: Resp
             platformWalletPublicKey, 
             developerShareBps = 9000, 
             platformShareBps = 1000,
-            developerOnboardingFee = 0  // In lamports, default 0
+            developerOnboardingFee = 0,  // In lamports, default 0
+            configChangeDelay = 86400  // In seconds, default 24h
         } = req.body;
 
         // Validate required fields
@@ -733,13 +776,23 @@ export const initializePlatformConfigController = async (req: Request, res: Resp
             });
         }
 
+        // Validate config change delay
+        const changeDelay = Number(configChangeDelay);
+        if (isNaN(changeDelay) || changeDelay < 0) {
+            return res.status(400).json({
+                success: false,
+                message: 'Config change delay must be a valid non-negative number of seconds'
+            });
+        }
+
         // Call the service
         const result = await initializePlatformConfigService(
             superAdminPubKey,
             platformWalletPubKey,
             devBps,
             platBps,
-            onboardingFee
+            onboardingFee,
+            changeDelay
         );
 
         if (result.success) {
@@ -835,9 +888,10 @@ export const updatePlatformConfigController = async (req: Request, res: Response
 };
 
 /**
- * Controller to update platform wallet (super admin only)
+ * Controller to propose a new platform wallet (super admin only)
+ * The new wallet must accept the proposal before it takes effect
  */
-export const updatePlatformWalletController = async (req: Request, res: Response) => {
+export const proposePlatformWalletController = async (req: Request, res: Response) => {
     try {
         const { 
             superAdminPublicKey, 
@@ -873,7 +927,7 @@ export const updatePlatformWalletController = async (req: Request, res: Response
         }
 
         // Call the service
-        const result = await updatePlatformWalletService(
+        const result = await proposePlatformWalletService(
             superAdminPubKey,
             newPlatformWalletPubKey
         );
@@ -884,19 +938,64 @@ export const updatePlatformWalletController = async (req: Request, res: Response
             return res.status(400).json(result);
         }
     } catch (err: any) {
-        console.error('❌ Error in update platform wallet controller:', err);
+        console.error('❌ Error in propose platform wallet controller:', err);
+        return res.status(500).json({
+            success: false,
+            message: 'Failed to propose platform wallet',
+            error: err.message || err
+        });
+    }
+};
+
+/**
+ * Controller to accept a pending platform wallet proposal (signed by the proposed key)
+ */
+export const acceptPlatformWalletController = async (req: Request, res: Response) => {
+    try {
+        const { newPlatformWalletPublicKey } = req.body;
+
+        // Validate required fields
+        if (!newPlatformWalletPublicKey) {
+            return res.status(400).json({
+                success: false,
+                message: 'New platform wallet public key is required'
+            });
+        }
+
+        // Validate public key format
+        let newPubKey: PublicKey;
+        try {
+            newPubKey = new PublicKey(newPlatformWalletPublicKey);
+        } catch (err) {
+            return res.status(400).json({
+                success: false,
+                message: 'Invalid public key format'
+            });
+        }
+
+        // Call the service
+        const result = await acceptPlatformWalletService(newPubKey);
+
+        if (result.success) {
+            return res.status(200).json(result);
+        } else {
+            return res.status(400).json(result);
+        }
+    } catch (err: any) {
+        console.error('❌ Error in accept platform wallet controller:', err);
         return res.status(500).json({
             success: false,
-            message: 'Failed to update platform wallet',
+            message: 'Failed to accept platform wallet',
             error: err.message || err
         });
     }
 };
 
 /**
- * Controller to transfer super admin role (super admin only)
+ * Controller to propose a new super admin (super admin only)
+ * The new super admin must accept the proposal before the role moves
  */
-export const transferSuperAdminController = async (req: Request, res: Response) => {
+export const proposeSuperAdminController = async (req: Request, res: Response) => {
     try {
         const { 
             superAdminPublicKey, 
@@ -932,7 +1031,7 @@ export const transferSuperAdminController = async (req: Request, res: Response)
         }
 
         // Call the service
-        const result = await transferSuperAdminService(
+        const result = await proposeSuperAdminService(
             superAdminPubKey,
             newSuperAdminPubKey
         );
@@ -943,10 +1042,54 @@ export const transferSuperAdminController = async (req: Request, res: Response)
             return res.status(400).json(result);
         }
     } catch (err: any) {
-        console.error('❌ Error in transfer super admin controller:', err);
+        console.error('❌ Error in propose super admin controller:', err);
+        return res.status(500).json({
+            success: false,
+            message: 'Failed to propose super admin',
+            error: err.message || err
+        });
+    }
+};
+
+/**
+ * Controller to accept a pending super admin proposal (signed by the proposed key)
+ */
+export const acceptSuperAdminController = async (req: Request, res: Response) => {
+    try {
+        const { newSuperAdminPublicKey } = req.body;
+
+        // Validate required fields
+        if (!newSuperAdminPublicKey) {
+            return res.status(400).json({
+                success: false,
+                message: 'New super admin public key is required'
+            });
+        }
+
+        // Validate public key format
+        let newPubKey: PublicKey;
+        try {
+            newPubKey = new PublicKey(newSuperAdminPublicKey);
+        } catch (err) {
+            return res.status(400).json({
+                success: false,
+                message: 'Invalid public key format'
+            });
+        }
+
+        // Call the service
+        const result = await acceptSuperAdminService(newPubKey);
+
+        if (result.success) {
+            return res.status(200).json(result);
+        } else {
+            return res.status(400).json(result);
+        }
+    } catch (err: any) {
+        console.error('❌ Error in accept super admin controller:', err);
         return res.status(500).json({
             success: false,
-            message: 'Failed to transfer super admin',
+            message: 'Failed to accept super admin',
             error: err.message || err
         });
     }
@@ -1,7 +1,7 @@
 //src/adminDashboard/adminDashboardRoutes.ts
 
 import { Router, RequestHandler } from 'express';
-import { checkPoolStatusController, getActiveStakersController, getAPYController, getDashboardStatsController, getDetailedStakersController, getRevenuePoolStatsController, getStakingPoolController, getStakingStatsController, getTournamentStatsController, initializePrizePoolController, confirmPrizePoolController, initializeRewardPoolController, initializeStakingPoolController, initializePlatformConfigController, updatePlatformConfigController, updatePlatformWalletController, transferSuperAdminController, getPlatformConfigController } from './adminDashboardController';
+import { checkPoolStatusController, getActiveStakersController, getAPYController, getDashboardStatsController, getDetailedStakersController, getRevenuePoolStatsController, getStakingPoolController, getStakingStatsController, getTournamentStatsController, initializePrizePoolController, confirmPrizePoolController, initializeRewardPoolController, linkRewardPoolController, initializeStakingPoolController, initializePlatformConfigController, updatePlatformConfigController, proposePlatformWalletController, acceptPlatformWalletController, proposeSuperAdminController, acceptSuperAdminController, getPlatformConfigController } from './adminDashboardController';
 
 const router = Router();
 
@@ -14,6 +14,9 @@ router.post('/initialize-staking-pool', initializeStakingPoolController as unkno
 // Route for initializing the reward pool
 router.post('/initialize-reward-pool', initializeRewardPoolController as unknown as RequestHandler);
 
+// Route for linking a reward pool to the staking pool it pays out to
+router.post('/link-reward-pool', linkRewardPoolController as unknown as RequestHandler);
+
 // Route for initializing the prize pool (creates transaction)
 router.post('/initialize-prize-pool', initializePrizePoolController as unknown as RequestHandler);
 
@@ -61,14 +64,24 @@ router.post('/platform-config/update',
   updatePlatformConfigController as unknown as RequestHandler
 );
 
-// Route to update platform wallet
-router.post('/platform-config/update-wallet',
-  updatePlatformWalletController as unknown as RequestHandler
+// Route to propose a new platform wallet
+router.post('/platform-config/propose-wallet',
+  proposePlatformWalletController as unknown as RequestHandler
+);
+
+// Route for the proposed platform wallet to accept the handover
+router.post('/platform-config/accept-wallet',
+  acceptPlatformWalletController as unknown as RequestHandler
+);
+
+// Route to propose a new super admin
+router.post('/platform-config/propose-admin',
+  proposeSuperAdminController as unknown as RequestHandler
 );
 
-// Route to transfer super admin role
-router.post('/platform-config/transfer-admin',
-  transferSuperAdminController as unknown as RequestHandler
+// Route for the proposed super admin to accept the role
+router.post('/platform-config/accept-admin',
+  acceptSuperAdminController as unknown as RequestHandler
 );
 
 // Route to get platform configuration
@@ -13,7 +13,7 @@ import {
   import { getProgram } from "../staking/services";
   dotenv.config();
 import * as anchor from "@project-serum/anchor";
-import { getStakingPoolPDA, getStakingEscrowPDA, getRewardPoolPDA, getRewardEscrowPDA, getTournamentPoolPDA, getPrizePoolPDA, getPrizeEscrowPDA, getPlatformConfigPDA, TokenType } from "../utils/getPDAs";
+import { getStakingPoolPDA, getStakingEscrowPDA, getRewardPoolPDA, getRewardEscrowPDA, getTournamentPoolPDA, getPrizePoolPDA, getPrizeEscrowPDA, getPlatformConfigPDA, getLockTiersPDA, getPoolRegistryPDA, resolveRoleAccount, TokenType } from "../utils/getPDAs";
 
 
 export interface StakingPoolAccount {
@@ -43,6 +43,8 @@ export interface PlatformConfigAccount {
     platformShareBps: anchor.BN;
     developerOnboardingFee: anchor.BN;
     onboardingFeeEnabled: boolean;
+    pendingSuperAdmin: PublicKey;
+    pendingPlatformWallet: PublicKey;
     isInitialized: boolean;
     bump: number;
 }
@@ -69,7 +71,7 @@ export interface DeveloperOnboardingRecordAccount {
       console.log("Admin PublicKey:", adminPublicKey.toBase58());
   
       // Get staking pool PDA
-      const stakingPoolPublicKey = getStakingPoolPDA(adminPublicKey, tokenType);
+      const stakingPoolPublicKey = getStakingPoolPDA(adminPublicKey, tokenType, mintPublicKey);
       console.log("🔹 Staking Pool PDA Address:", stakingPoolPublicKey.toBase58());
   
       // Get escrow PDA
@@ -91,12 +93,29 @@ export interface DeveloperOnboardingRecordAccount {
         .accounts({
           stakingPool: stakingPoolPublicKey,
           poolEscrowAccount: poolEscrowAccountPublicKey,
+          poolRegistry: getPoolRegistryPDA(),
+          platformConfig: getPlatformConfigPDA(),
           mint: actualMint,  // ✅ Use SystemProgram for SOL
           admin: adminPublicKey,
           systemProgram: SystemProgram.programId,
           tokenProgram: TOKEN_2022_PROGRAM_ID,
         })
         .transaction();
+
+      // Create the pool's lock tier registry in the same transaction so staking works right away
+      const lockTiersPublicKey = getLockTiersPDA(stakingPoolPublicKey);
+      console.log("🔹 Lock Tiers PDA Address:", lockTiersPublicKey.toBase58());
+
+      const lockTiersInstruction = await program.methods
+        .initializeLockTiers()
+        .accounts({
+          stakingPool: stakingPoolPublicKey,
+          lockTiers: lockTiersPublicKey,
+          admin: adminPublicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction();
+      transaction.add(lockTiersInstruction);
   
       transaction.recentBlockhash = blockhash;
       transaction.feePayer = adminPublicKey;
@@ -231,7 +250,7 @@ export const initializeRewardPoolService = async (
     const { program, connection } = getProgram();
 
     // Derive PDAs
-    const rewardPoolPublicKey = getRewardPoolPDA(adminPublicKey, tokenType);
+    const rewardPoolPublicKey = getRewardPoolPDA(adminPublicKey, tokenType, mintPublicKey);
 
     const rewardEscrowPublicKey = getRewardEscrowPDA(rewardPoolPublicKey);
     const actualMint = tokenType === TokenType.SOL 
@@ -270,6 +289,47 @@ export const initializeRewardPoolService = async (
   }
 };
 
+  // ✅ Link a reward pool to the staking pool it pays out to (pool admin only)
+export const linkRewardPoolService = async (
+  stakingMintPublicKey: PublicKey,
+  stakingTokenType: TokenType,
+  rewardMintPublicKey: PublicKey,
+  rewardTokenType: TokenType,
+  adminPublicKey: PublicKey
+) => {
+  try {
+    const { program, connection } = getProgram();
+
+    const stakingPoolPublicKey = getStakingPoolPDA(adminPublicKey, stakingTokenType, stakingMintPublicKey);
+    const rewardPoolPublicKey = getRewardPoolPDA(adminPublicKey, rewardTokenType, rewardMintPublicKey);
+
+    const { blockhash } = await connection.getLatestBlockhash("finalized");
+    const transaction = await program.methods
+      .linkRewardPool()
+      .accounts({
+        stakingPool: stakingPoolPublicKey,
+        rewardPool: rewardPoolPublicKey,
+        poolRegistry: getPoolRegistryPDA(),
+        admin: adminPublicKey,
+      })
+      .transaction();
+
+    transaction.recentBlockhash = blockhash;
+    transaction.feePayer = adminPublicKey;
+
+    return {
+      success: true,
+      message: "Transaction created successfully!",
+      stakingPool: stakingPoolPublicKey.toBase58(),
+      rewardPool: rewardPoolPublicKey.toBase58(),
+      transaction: transaction.serialize({ requireAllSignatures: false }).toString("base64"),
+    };
+  } catch (err: any) {
+    console.error("❌ Error creating linkRewardPool tx:", err);
+    return { success: false, message: `Error creating tx: ${err.message || err}` };
+  }
+};
+
   
  // ✅ Function to check pool status for staking and reward pools
  // Uses super admin from platform config (pools are global)
@@ -353,7 +413,8 @@ export const initializePlatformConfigService = async (
   platformWalletPublicKey: PublicKey,
   developerShareBps: number = 9000,
   platformShareBps: number = 1000,
-  developerOnboardingFee: number = 0  // In lamports, default 0
+  developerOnboardingFee: number = 0,  // In lamports, default 0
+  configChangeDelay: number = 86400  // Timelock for share changes in seconds, default 24h
 ) => {
   try {
     const { program, connection } = getProgram();
@@ -384,6 +445,14 @@ export const initializePlatformConfigService = async (
       };
     }
 
+    // Validate config change delay
+    if (configChangeDelay < 0) {
+      return {
+        success: false,
+        message: "Config change delay cannot be negative"
+      };
+    }
+
     console.log("Initializing Platform Config:");
     console.log("🔹 Platform Config PDA:", platformConfigPDA.toString());
     console.log("🔹 Super Admin:", superAdminPublicKey.toString());
@@ -391,6 +460,7 @@ export const initializePlatformConfigService = async (
     console.log(`🔹 Developer Share: ${developerShareBps / 100}%`);
     console.log(`🔹 Platform Share: ${platformShareBps / 100}%`);
     console.log(`🔹 Developer Onboarding Fee: ${developerOnboardingFee} lamports`);
+    console.log(`🔹 Config Change Delay: ${configChangeDelay} seconds`);
 
     const { blockhash } = await connection.getLatestBlockhash("finalized");
 
@@ -398,7 +468,8 @@ export const initializePlatformConfigService = async (
       .initializePlatformConfig(
         developerShareBps, 
         platformShareBps,
-        new anchor.BN(developerOnboardingFee)
+        new anchor.BN(developerOnboardingFee),
+        new anchor.BN(configChangeDelay)
       )
       .accounts({
         platformConfig: platformConfigPDA,
@@ -427,8 +498,9 @@ export const initializePlatformConfigService = async (
 };
 
 /**
- * Update platform configuration (super admin only)
- * Allows adjusting the revenue share percentages
+ * Update platform configuration (super admin or fee manager)
+ * Allows adjusting the revenue share percentages; the change is queued
+ * and applied once the config change delay has elapsed
  */
 export const updatePlatformConfigService = async (
   superAdminPublicKey: PublicKey,
@@ -466,7 +538,8 @@ export const updatePlatformConfigService = async (
       .updatePlatformConfig(developerShareBps, platformShareBps)
       .accounts({
         platformConfig: platformConfigPDA,
-        superAdmin: superAdminPublicKey,
+        roleAccount: await resolveRoleAccount(superAdminPublicKey),
+        authority: superAdminPublicKey,
       })
       .instruction();
 
@@ -490,9 +563,10 @@ export const updatePlatformConfigService = async (
 };
 
 /**
- * Update platform wallet (super admin only)
+ * Propose a new platform wallet (super admin only)
+ * The change only takes effect once the new wallet accepts it
  */
-export const updatePlatformWalletService = async (
+export const proposePlatformWalletService = async (
   superAdminPublicKey: PublicKey,
   newPlatformWalletPublicKey: PublicKey
 ) => {
@@ -500,13 +574,13 @@ export const updatePlatformWalletService = async (
     const { program, connection } = getProgram();
     const platformConfigPDA = getPlatformConfigPDA();
 
-    console.log("Updating Platform Wallet:");
+    console.log("Proposing Platform Wallet:");
     console.log("🔹 Platform Config PDA:", platformConfigPDA.toString());
     console.log("🔹 Super Admin:", superAdminPublicKey.toString());
     console.log("🔹 New Platform Wallet:", newPlatformWalletPublicKey.toString());
 
     const instruction = await program.methods
-      .updatePlatformWallet()
+      .proposePlatformWallet()
       .accounts({
         platformConfig: platformConfigPDA,
         newPlatformWallet: newPlatformWalletPublicKey,
@@ -521,22 +595,64 @@ export const updatePlatformWalletService = async (
 
     return {
       success: true,
-      message: "Platform wallet update transaction prepared",
+      message: "Platform wallet proposal transaction prepared",
+      transaction: transaction.serialize({ requireAllSignatures: false }).toString('base64')
+    };
+  } catch (error: any) {
+    console.error("❌ Error proposing platform wallet:", error);
+    return { 
+      success: false, 
+      message: `Error proposing platform wallet: ${error.message || error}` 
+    };
+  }
+};
+
+/**
+ * Accept a pending platform wallet proposal (signed by the proposed wallet)
+ */
+export const acceptPlatformWalletService = async (
+  newPlatformWalletPublicKey: PublicKey
+) => {
+  try {
+    const { program, connection } = getProgram();
+    const platformConfigPDA = getPlatformConfigPDA();
+
+    console.log("Accepting Platform Wallet:");
+    console.log("🔹 Platform Config PDA:", platformConfigPDA.toString());
+    console.log("🔹 New Platform Wallet:", newPlatformWalletPublicKey.toString());
+
+    const instruction = await program.methods
+      .acceptPlatformWallet()
+      .accounts({
+        platformConfig: platformConfigPDA,
+        newPlatformWallet: newPlatformWalletPublicKey,
+      })
+      .instruction();
+
+    const transaction = new Transaction().add(instruction);
+    const { blockhash } = await connection.getLatestBlockhash("finalized");
+    transaction.recentBlockhash = blockhash;
+    transaction.feePayer = newPlatformWalletPublicKey;
+
+    return {
+      success: true,
+      message: "Platform wallet acceptance transaction prepared",
       transaction: transaction.serialize({ requireAllSignatures: false }).toString('base64')
     };
   } catch (error: any) {
-    console.error("❌ Error updating platform wallet:", error);
+    console.error("❌ Error accepting platform wallet:", error);
     return { 
       success: false, 
-      message: `Error updating platform wallet: ${error.message || error}` 
+      message: `Error accepting platform wallet: ${error.message || error}` 
     };
   }
 };
 
 /**
- * Transfer super admin role (super admin only)
+ * Propose a new super admin (super admin only)
+ * The role only moves once the new super admin accepts it
  */
-export const transferSuperAdminService = async (
+export const proposeSuperAdminService = async (
   superAdminPublicKey: PublicKey,
   newSuperAdminPublicKey: PublicKey
 ) => {
@@ -544,13 +660,13 @@ export const transferSuperAdminService = async (
     const { program, connection } = getProgram();
     const platformConfigPDA = getPlatformConfigPDA();
 
-    console.log("Transferring Super Admin:");
+    console.log("Proposing Super Admin:");
     console.log("🔹 Platform Config PDA:", platformConfigPDA.toString());
     console.log("🔹 Current Super Admin:", superAdminPublicKey.toString());
     console.log("🔹 New Super Admin:", newSuperAdminPublicKey.toString());
 
     const instruction = await program.methods
-      .transferSuperAdmin()
+      .proposeSuperAdmin()
       .accounts({
         platformConfig: platformConfigPDA,
         newSuperAdmin: newSuperAdminPublicKey,
@@ -565,14 +681,55 @@ export const transferSuperAdminService = async (
 
     return {
       success: true,
-      message: "Super admin transfer transaction prepared",
+      message: "Super admin proposal transaction prepared",
+      transaction: transaction.serialize({ requireAllSignatures: false }).toString('base64')
+    };
+  } catch (error: any) {
+    console.error("❌ Error proposing super admin:", error);
+    return { 
+      success: false, 
+      message: `Error proposing super admin: ${error.message || error}` 
+    };
+  }
+};
+
+/**
+ * Accept a pending super admin proposal (signed by the proposed super admin)
+ */
+export const acceptSuperAdminService = async (
+  newSuperAdminPublicKey: PublicKey
+) => {
+  try {
+    const { program, connection } = getProgram();
+    const platformConfigPDA = getPlatformConfigPDA();
+
+    console.log("Accepting Super Admin:");
+    console.log("🔹 Platform Config PDA:", platformConfigPDA.toString());
+    console.log("🔹 New Super Admin:", newSuperAdminPublicKey.toString());
+
+    const instruction = await program.methods
+      .acceptSuperAdmin()
+      .accounts({
+        platformConfig: platformConfigPDA,
+        newSuperAdmin: newSuperAdminPublicKey,
+      })
+      .instruction();
+
+    const transaction = new Transaction().add(instruction);
+    const { blockhash } = await connection.getLatestBlockhash("finalized");
+    transaction.recentBlockhash = blockhash;
+    transaction.feePayer = newSuperAdminPublicKey;
+
+    return {
+      success: true,
+      message: "Super admin acceptance transaction prepared",
       transaction: transaction.serialize({ requireAllSignatures: false }).toString('base64')
     };
   } catch (error: any) {
-    console.error("❌ Error transferring super admin:", error);
+    console.error("❌ Error accepting super admin:", error);
     return { 
       success: false, 
-      message: `Error transferring super admin: ${error.message || error}` 
+      message: `Error accepting super admin: ${error.message || error}` 
     };
   }
 };
@@ -598,6 +755,8 @@ export const getPlatformConfigService = async () => {
         platformSharePercent: Number(config.platformShareBps) / 100,
         developerOnboardingFee: Number(config.developerOnboardingFee),
         onboardingFeeEnabled: config.onboardingFeeEnabled,
+        pendingSuperAdmin: config.pendingSuperAdmin.toString(),
+        pendingPlatformWallet: config.pendingPlatformWallet.toString(),
         isInitialized: config.isInitialized,
         bump: config.bump,
       } as {
@@ -609,6 +768,8 @@ export const getPlatformConfigService = async () => {
         platformSharePercent: number;
         developerOnboardingFee: number;
         onboardingFeeEnabled: boolean;
+        pendingSuperAdmin: string;
+        pendingPlatformWallet: string;
         isInitialized: boolean;
         bump: number;
       }
@@ -11,7 +11,7 @@ dotenv.config();
 import * as anchor from "@project-serum/anchor";
 import { UserStakingAccount } from "../staking/services";
 import { StakingPoolAccount } from "./services";
-import { SEEDS, TokenType, getStakingPoolPDA, getUserStakingPDA } from "../utils/getPDAs";
+import { SEEDS, TokenType, getPoolSeedMint, getStakingPoolPDA, getUserStakingPDA } from "../utils/getPDAs";
 
 /**
  * Helper function to format token amounts properly
@@ -57,7 +57,7 @@ export const getStakingPoolData = async (tokenType: TokenType) => {
 
         // Derive the staking pool PDA using super admin
         const [stakingPoolPublicKey] = PublicKey.findProgramAddressSync(
-            [Buffer.from(SEEDS.STAKING_POOL), superAdminPublicKey.toBuffer(), Buffer.from([tokenType])],
+            [Buffer.from(SEEDS.STAKING_POOL), superAdminPublicKey.toBuffer(), getPoolSeedMint(tokenType).toBuffer(), Buffer.from([tokenType])],
             program.programId
         );
 
@@ -182,8 +182,8 @@ export const getActiveStakers = async (adminPublicKey?: PublicKey, tokenType?: T
         const poolStakingAccounts = userStakingAccounts.filter(account => {
             try {
                 const userData = account.account as UserStakingAccount;
-                // Derive what the PDA should be for this pool + owner
-                const expectedPDA = getUserStakingPDA(stakingPoolPublicKey, userData.owner);
+                // Derive what the PDA should be for this pool + owner + position
+                const expectedPDA = getUserStakingPDA(stakingPoolPublicKey, userData.owner, userData.positionId);
                 // Check if this account matches our expected PDA
                 return account.publicKey.equals(expectedPDA);
             } catch {
@@ -3,6 +3,7 @@ import { ref, get, set, push, update } from "firebase/database";
 import { db } from "../config/firebase";
 import { getTournamentPool, registerForTournamentService, initializeTournamentPoolService, getPrizePoolService, getTotalPrizePoolsFundsService, getTotalTournamentPoolsFundsService, getTotalTournamentEntryFeesService } from './services';
 import { getTournamentLeaderboard, updateParticipantScore, getTournamentsByGame } from "./leaderboardService";
+import { DEFAULT_SPLITS } from "../revenue/services";
 import { getTournamentLeaderboardAgainstAdmin, getAdminTournamentsLeaderboards } from "./adminLeaderboardService";
 import { PublicKey, Transaction, VersionedTransaction } from "@solana/web3.js";
 import bs58 from "bs58";
@@ -53,6 +54,12 @@ export async function createTournament(req: Request, res: Response) {
   try {
     const { name, description, startTime, endTime, gameId, tokenType } = req.body as Tournament;
     const { mint, adminPublicKey, entryFee } = req.body;
+    const {
+      prizePercentage = DEFAULT_SPLITS.PRIZE_POOL,
+      revenuePercentage = DEFAULT_SPLITS.REVENUE_POOL,
+      stakingPercentage = DEFAULT_SPLITS.STAKING_REWARD_POOL,
+      burnPercentage = DEFAULT_SPLITS.BURN
+    } = req.body;
     const maxParticipants = 100;
 
     if (!name || !gameId || !startTime || !endTime || !adminPublicKey || !entryFee || !mint || tokenType === undefined || tokenType === null) {
@@ -64,6 +71,16 @@ export async function createTournament(req: Request, res: Response) {
       return res.status(400).json({ message: "tokenType must be 0 (SPL) or 1 (SOL)" });
     }
 
+    // The revenue split is fixed on-chain when the tournament pool is created
+    const splits = [prizePercentage, revenuePercentage, stakingPercentage, burnPercentage].map(Number);
+    if (splits.some((p) => isNaN(p) || p < 0 || p > 100)) {
+      return res.status(400).json({ message: "All percentages must be between 0 and 100" });
+    }
+    const totalPercentage = splits.reduce((sum, p) => sum + p, 0);
+    if (totalPercentage !== 100) {
+      return res.status(400).json({ message: `Percentages must add up to 100%. Current total: ${totalPercentage}%` });
+    }
+
     // ✅ FIX: Ensure endTime is in SECONDS (Unix timestamp)
     let endTimeInUnix: number;
     if (typeof endTime === 'string') {
@@ -111,7 +128,11 @@ export async function createTournament(req: Request, res: Response) {
       maxParticipants,
       endTimeInUnix, // ✅ Pass as seconds
       mint,
-      tt as TokenType
+      tt as TokenType,
+      splits[0],
+      splits[1],
+      splits[2],
+      splits[3]
     );
 
     if (!transaction.success) {
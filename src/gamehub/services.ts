@@ -13,7 +13,7 @@ import { BN } from "bn.js";
 import { createAssociatedTokenAccount, getProgram } from "../staking/services";
 import { ref, get } from "firebase/database";
 import { db } from "../config/firebase";
-import { getPrizeEscrowPDA, getPrizePoolPDA, getRegistrationPDA, getTournamentEscrowPDA, TokenType } from "../utils/getPDAs";
+import { getPlatformConfigPDA, getPrizeEscrowPDA, getPrizePoolPDA, getRegistrationPDA, getTournamentEscrowPDA, TokenType } from "../utils/getPDAs";
 import {getTournamentPoolPDA} from "../utils/getPDAs";
 
 dotenv.config();
@@ -27,7 +27,11 @@ export const initializeTournamentPoolService = async (
   maxParticipants: number,
   endTime: number,
   mintPublicKey: PublicKey,
-  tokenType: TokenType
+  tokenType: TokenType,
+  prizePercentage: number,
+  revenuePercentage: number,
+  stakingPercentage: number,
+  burnPercentage: number
 ) => {
   try {
     const { program, connection } = getProgram();
@@ -59,11 +63,16 @@ export const initializeTournamentPoolService = async (
         entryFeeBN,
         maxParticipantsBN,
         endTimeBN,
-        tokenTypeArg
+        tokenTypeArg,
+        prizePercentage,
+        revenuePercentage,
+        stakingPercentage,
+        burnPercentage
       )
       .accounts({
         creator: adminPublicKey,
         tournamentPool: tournamentPoolPublicKey,
+        platformConfig: getPlatformConfigPDA(),
         poolEscrowAccount: poolEscrowAccountPublicKey,
         mint: mintPublicKey,
         systemProgram: SystemProgram.programId,
@@ -129,6 +138,10 @@ export const getTournamentPool = async (tournamentId: string, adminPublicKey: Pu
         endTime: new Date(tournamentPoolData.endTime * 1000).toISOString(),
         isActive: tournamentPoolData.isActive,
         tokenType: tokenType,
+        prizePercentage: tournamentPoolData.prizePercentage,
+        revenuePercentage: tournamentPoolData.revenuePercentage,
+        stakingPercentage: tournamentPoolData.stakingPercentage,
+        burnPercentage: tournamentPoolData.burnPercentage,
       }
     };
   } catch (err: any) {
@@ -408,6 +421,7 @@ export const registerForTournamentService = async (
       .accounts({
         user: userPublicKey,
         tournamentPool: tournamentPoolPublicKey,
+        platformConfig: getPlatformConfigPDA(),
         registrationAccount: registrationAccountPublicKey,
         userTokenAccount: userTokenAccountPublicKey,
         poolEscrowAccount: poolEscrowAccountPublicKey,
@@ -467,6 +481,10 @@ interface TournamentPoolAccount {
   totalFunds: anchor.BN; // Total funds accumulated in the pool
   bump: number; // Bump seed for the tournament pool account
   tokenType: TokenType;
+  prizePercentage: number; // Revenue split fixed at creation
+  revenuePercentage: number;
+  stakingPercentage: number;
+  burnPercentage: number;
 }
 
 interface PrizePoolAccount {
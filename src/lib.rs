@@ -1,7 +1,7 @@
 use anchor_lang::prelude::InterfaceAccount;
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
-use anchor_spl::token_2022::{self, Burn, Token2022, TransferChecked};
+use anchor_spl::token_2022::{self, Burn, MintTo, Token2022, TransferChecked};
 use anchor_spl::token_interface::{Mint, TokenAccount};
 use solana_program::program::invoke;
 use solana_program::program::invoke_signed; // ✅ ADD THIS
@@ -11,6 +11,10 @@ declare_id!("C1tiBNKdWaH1p2RCpy2w3UetstzgwxKE7mBNUQyHyx2a");
 
 // Smart contract up to date with onboarding fee feature
 
+// Account layouts and PDA seeds are not compatible with the previous release:
+// fields were added ahead of `bump`, pool PDAs are seeded by mint and stake
+// positions by id. Ship this as a fresh deployment, not an in-place upgrade.
+
 // ==============================
 // SEED CONSTANTS
 // ==============================
@@ -27,6 +31,17 @@ pub const SEED_REWARD_ESCROW: &[u8] = b"reward_escrow";
 pub const SEED_SOL_VAULT: &[u8] = b"sol_vault";
 pub const SEED_PLATFORM_CONFIG: &[u8] = b"platform_config";
 pub const SEED_DEVELOPER_ONBOARDING: &[u8] = b"developer_onboarding";
+pub const SEED_ADMIN_COUNCIL: &[u8] = b"admin_council";
+pub const SEED_COUNCIL_PROPOSAL: &[u8] = b"council_proposal";
+pub const SEED_ROLE: &[u8] = b"role";
+pub const SEED_LOCK_TIERS: &[u8] = b"lock_tiers";
+pub const SEED_USER_STAKE_INDEX: &[u8] = b"user_stake_index";
+pub const SEED_RECEIPT_MINT: &[u8] = b"receipt_mint";
+pub const SEED_UNBONDING_TICKET: &[u8] = b"unbonding_ticket";
+pub const SEED_GOVERNANCE_PROPOSAL: &[u8] = b"governance_proposal";
+pub const SEED_VOTE_RECORD: &[u8] = b"vote_record";
+pub const SEED_EPOCH_SNAPSHOT: &[u8] = b"epoch_snapshot";
+pub const SEED_POOL_REGISTRY: &[u8] = b"pool_registry";
 
 // ==============================
 // PROTOCOL LIMITS & CONSTANTS
@@ -36,21 +51,72 @@ pub const MAX_TOURNAMENT_PARTICIPANTS: u16 = 1000;
 pub const MAX_TOURNAMENT_DURATION_DAYS: i64 = 90;
 pub const MIN_TOURNAMENT_DURATION_SECONDS: i64 = 120; // 1 hour
 
+// Emergency pause flags - one bit per instruction family
+pub const PAUSE_STAKE: u16 = 1 << 0;
+pub const PAUSE_UNSTAKE: u16 = 1 << 1;
+pub const PAUSE_CLAIM: u16 = 1 << 2;
+pub const PAUSE_REGISTRATION: u16 = 1 << 3;
+pub const PAUSE_DISTRIBUTION: u16 = 1 << 4;
+// Withdraw-only mode: block all inflows and payouts while unstake/claim stay open
+pub const PAUSE_WITHDRAW_ONLY: u16 = PAUSE_STAKE | PAUSE_REGISTRATION | PAUSE_DISTRIBUTION;
+pub const PAUSE_ALL: u16 =
+    PAUSE_STAKE | PAUSE_UNSTAKE | PAUSE_CLAIM | PAUSE_REGISTRATION | PAUSE_DISTRIBUTION;
+
+// Admin roles - bitset stored on RoleAccount; super_admin implicitly holds all of them
+pub const ROLE_FEE_MANAGER: u8 = 1 << 0; // revenue shares, distribution bounds, onboarding fee
+pub const ROLE_TREASURY: u8 = 1 << 1; // platform wallet changes
+pub const ROLE_PAUSER: u8 = 1 << 2; // emergency pause flags
+pub const ROLE_DEVELOPER_MODERATOR: u8 = 1 << 3; // developer onboarding records
+pub const ALL_ROLES: u8 =
+    ROLE_FEE_MANAGER | ROLE_TREASURY | ROLE_PAUSER | ROLE_DEVELOPER_MODERATOR;
+
+// Admin council limits (approvals are tracked in a u16 bitmask)
+pub const MAX_COUNCIL_MEMBERS: usize = 10;
+
+// Council proposals can no longer be approved or executed this long after creation
+pub const COUNCIL_PROPOSAL_EXPIRY: i64 = 7 * 24 * 60 * 60;
+
+// Canonical staking/reward pool bindings kept on the platform config
+pub const MAX_POOL_BINDINGS: usize = 8;
+
+// Passed governance proposals must be executed within this long after voting ends
+pub const GOVERNANCE_EXECUTION_WINDOW: i64 = 7 * 24 * 60 * 60;
+
+// Staking pools listed in the global pool registry
+pub const MAX_REGISTERED_POOLS: usize = 32;
+
 // Fixed-point precision for reward accumulator
 const ACC_PRECISION: u128 = 1_000_000_000_000; // 1e12
 const BPS_DENOMINATOR: u64 = 10_000; // 100% in basis points
 
-// Lock period multipliers in basis points
+// Lock tier registry limits
+pub const MAX_LOCK_TIERS: usize = 8;
+pub const MAX_LOCK_MULTIPLIER_BPS: u64 = 50_000; // 5.0x
+
+// Open stake positions per user per pool
+pub const MAX_STAKE_POSITIONS: usize = 16;
+
+// Weight history kept per position (oldest entries are dropped first)
+pub const MAX_WEIGHT_CHECKPOINTS: usize = 8;
+
+// Extra reward assets a staking pool can pay besides its primary reward pool
+pub const MAX_SECONDARY_REWARDS: usize = 3;
+// claim_rewards remaining accounts per secondary: reward pool, reward escrow, user token account, mint
+pub const SECONDARY_CLAIM_ACCOUNTS: usize = 4;
+
+// Default lock tiers seeded by `initialize_lock_tiers`
+// Multipliers in basis points
 const MULTIPLIER_1M_BPS: u64 = 10_000; // 1.0x
 const MULTIPLIER_3M_BPS: u64 = 12_000; // 1.2x
 const MULTIPLIER_6M_BPS: u64 = 15_000; // 1.5x
 const MULTIPLIER_12M_BPS: u64 = 20_000; // 2.0x
 
-// Lock durations in seconds (dev mode - minutes)
-const ONE_MONTH: i64 = 2 * 60; // 2 minutes
-const THREE_MONTHS: i64 = 5 * 60; // 5 minutes
-const SIX_MONTHS: i64 = 10 * 60; // 10 minutes
-const TWELVE_MONTHS: i64 = 20 * 60; // 20 minutes
+// Lock durations in seconds; pools can retune them with update_lock_tier
+const ONE_DAY: i64 = 24 * 60 * 60;
+const ONE_MONTH: i64 = 30 * ONE_DAY;
+const THREE_MONTHS: i64 = 90 * ONE_DAY;
+const SIX_MONTHS: i64 = 180 * ONE_DAY;
+const TWELVE_MONTHS: i64 = 365 * ONE_DAY;
 
 // Default revenue distribution percentages
 pub const DEFAULT_PRIZE_PERCENTAGE: u8 = 40;
@@ -76,28 +142,738 @@ impl Default for TokenType {
 // ==============================
 // HELPER FUNCTIONS
 // ==============================
-fn lock_multiplier_bps(lock_duration: i64) -> u64 {
-    if lock_duration == ONE_MONTH {
-        MULTIPLIER_1M_BPS
-    } else if lock_duration == THREE_MONTHS {
-        MULTIPLIER_3M_BPS
-    } else if lock_duration == SIX_MONTHS {
-        MULTIPLIER_6M_BPS
-    } else if lock_duration == TWELVE_MONTHS {
-        MULTIPLIER_12M_BPS
+fn validate_lock_tier(lock_duration: i64, multiplier_bps: u64) -> Result<()> {
+    require!(lock_duration > 0, StakingError::InvalidLockDuration);
+    require!(
+        multiplier_bps >= BPS_DENOMINATOR && multiplier_bps <= MAX_LOCK_MULTIPLIER_BPS,
+        StakingError::InvalidLockMultiplier
+    );
+    Ok(())
+}
+
+fn validate_distribution_percentages(
+    prize_percentage: u8,
+    revenue_percentage: u8,
+    staking_percentage: u8,
+    burn_percentage: u8,
+) -> Result<()> {
+    let total = prize_percentage
+        .checked_add(revenue_percentage)
+        .and_then(|sum| sum.checked_add(staking_percentage))
+        .and_then(|sum| sum.checked_add(burn_percentage))
+        .ok_or(TournamentError::MathOverflow)?;
+
+    require!(total == 100, TournamentError::InvalidPercentages);
+    Ok(())
+}
+
+fn validate_distribution_bounds(
+    bounds: &DistributionBounds,
+    prize_percentage: u8,
+    revenue_percentage: u8,
+    staking_percentage: u8,
+    burn_percentage: u8,
+) -> Result<()> {
+    require!(
+        prize_percentage >= bounds.min_prize_percentage
+            && prize_percentage <= bounds.max_prize_percentage,
+        TournamentError::PrizePercentageOutOfBounds
+    );
+    require!(
+        revenue_percentage >= bounds.min_revenue_percentage
+            && revenue_percentage <= bounds.max_revenue_percentage,
+        TournamentError::RevenuePercentageOutOfBounds
+    );
+    require!(
+        staking_percentage >= bounds.min_staking_percentage
+            && staking_percentage <= bounds.max_staking_percentage,
+        TournamentError::StakingPercentageOutOfBounds
+    );
+    require!(
+        burn_percentage >= bounds.min_burn_percentage
+            && burn_percentage <= bounds.max_burn_percentage,
+        TournamentError::BurnPercentageOutOfBounds
+    );
+    Ok(())
+}
+
+/// Pay staking rewards out of a reward pool and reduce its tracked funds
+fn pay_rewards<'info>(
+    reward_pool: &mut Account<'info, RewardPool>,
+    recipient: AccountInfo<'info>,
+    recipient_token_account: AccountInfo<'info>,
+    reward_escrow_account: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    require!(
+        reward_pool.total_funds >= amount,
+        TournamentError::InsufficientFunds
+    );
+
+    match reward_pool.token_type {
+        TokenType::SOL => {
+            // Transfer SOL from reward pool to recipient
+            let reward_pool_info = reward_pool.to_account_info();
+
+            **reward_pool_info.try_borrow_mut_lamports()? = reward_pool_info
+                .lamports()
+                .checked_sub(amount)
+                .ok_or(StakingError::MathOverflow)?;
+
+            **recipient.try_borrow_mut_lamports()? = recipient
+                .lamports()
+                .checked_add(amount)
+                .ok_or(StakingError::MathOverflow)?;
+
+            msg!("✅ Paid {} lamports SOL rewards", amount);
+        }
+        TokenType::SPL => {
+            // Transfer SPL tokens from reward escrow
+            let decimals = {
+                let mint_data = mint.try_borrow_data()?;
+                let mint = Mint::try_deserialize(&mut &mint_data[..])?;
+                mint.decimals
+            };
+
+            let reward_admin = reward_pool.admin;
+            let reward_seed_mint = reward_pool.seed_mint();
+            let token_type_seed = [reward_pool.token_type as u8];
+            let reward_bump = reward_pool.bump;
+            let reward_pool_seeds = &[
+                SEED_REWARD_POOL,
+                reward_admin.as_ref(),
+                reward_seed_mint.as_ref(),
+                token_type_seed.as_ref(),
+                &[reward_bump],
+            ];
+            let signer_seeds: &[&[&[u8]]] = &[reward_pool_seeds];
+
+            token_2022::transfer_checked(
+                CpiContext::new_with_signer(
+                    token_program,
+                    TransferChecked {
+                        from: reward_escrow_account,
+                        to: recipient_token_account,
+                        mint,
+                        authority: reward_pool.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+                decimals,
+            )?;
+
+            msg!("✅ Paid {} SPL token rewards", amount);
+        }
+    }
+
+    reward_pool.total_funds = reward_pool.total_funds.saturating_sub(amount);
+
+    Ok(())
+}
+
+/// Mint component of staking and reward pool seeds; SOL pools use Pubkey::default()
+pub fn pool_seed_mint(token_type: TokenType, mint: &Pubkey) -> Pubkey {
+    match token_type {
+        TokenType::SOL => Pubkey::default(),
+        TokenType::SPL => *mint,
+    }
+}
+
+/// Address of a staking pool's principal escrow: the SOL vault or the SPL escrow
+fn staking_escrow_address(staking_pool: &Account<StakingPool>, program_id: &Pubkey) -> (Pubkey, u8) {
+    let staking_pool_key = staking_pool.key();
+    let seed = match staking_pool.token_type {
+        TokenType::SOL => SEED_SOL_VAULT,
+        TokenType::SPL => SEED_ESCROW,
+    };
+    Pubkey::find_program_address(&[seed, staking_pool_key.as_ref()], program_id)
+}
+
+/// Burn `shares` receipt tokens after harvesting, removing their underlying from the pool
+/// Returns the underlying amount; the caller pays it out or moves it into a ticket
+#[allow(clippy::too_many_arguments)]
+fn redeem_liquid_shares<'info>(
+    staking_pool: &mut Account<'info, StakingPool>,
+    reward_pool: &mut Account<'info, RewardPool>,
+    receipt_mint: &InterfaceAccount<'info, Mint>,
+    user_receipt_account: AccountInfo<'info>,
+    user: AccountInfo<'info>,
+    pool_escrow_account: AccountInfo<'info>,
+    reward_escrow_account: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    shares: u64,
+) -> Result<u64> {
+    require!(shares > 0, StakingError::InvalidShareAmount);
+
+    staking_pool.update_pool(Clock::get()?.unix_timestamp)?;
+    harvest_liquid_rewards(
+        staking_pool,
+        reward_pool,
+        pool_escrow_account,
+        reward_escrow_account,
+        mint,
+        token_program.clone(),
+    )?;
+
+    let supply = receipt_mint.supply;
+    require!(shares <= supply, StakingError::InvalidShareAmount);
+    let amount: u64 = (shares as u128)
+        .saturating_mul(staking_pool.total_staked as u128)
+        .checked_div(supply as u128)
+        .ok_or(StakingError::MathOverflow)? as u64;
+    require!(amount > 0, StakingError::InvalidShareAmount);
+
+    token_2022::burn(
+        CpiContext::new(
+            token_program,
+            Burn {
+                mint: receipt_mint.to_account_info(),
+                from: user_receipt_account,
+                authority: user,
+            },
+        ),
+        shares,
+    )?;
+
+    staking_pool.total_staked = staking_pool
+        .total_staked
+        .checked_sub(amount)
+        .ok_or(StakingError::MathOverflow)?;
+    staking_pool.total_weight = staking_pool
+        .total_weight
+        .checked_sub(amount as u128)
+        .ok_or(StakingError::MathOverflow)?;
+    staking_pool.liquid_reward_debt = staking_pool
+        .total_weight
+        .saturating_mul(staking_pool.acc_reward_per_weight)
+        .checked_div(ACC_PRECISION)
+        .unwrap_or(0);
+
+    Ok(amount)
+}
+
+/// Move rewards accrued by a liquid pool into its principal so they raise the exchange rate
+/// Rewards the reward pool cannot cover stay in `liquid_pending_rewards`
+fn harvest_liquid_rewards<'info>(
+    staking_pool: &mut Account<'info, StakingPool>,
+    reward_pool: &mut Account<'info, RewardPool>,
+    pool_escrow_account: AccountInfo<'info>,
+    reward_escrow_account: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+) -> Result<u64> {
+    let accumulated: u128 = staking_pool
+        .total_weight
+        .saturating_mul(staking_pool.acc_reward_per_weight)
+        .checked_div(ACC_PRECISION)
+        .unwrap_or(0);
+    let pending_now: u128 = accumulated.saturating_sub(staking_pool.liquid_reward_debt);
+    let pending: u64 = (pending_now.min(u128::from(u64::MAX)) as u64)
+        .saturating_add(staking_pool.liquid_pending_rewards);
+
+    let payable = pending.min(reward_pool.total_funds);
+    if payable > 0 {
+        pay_rewards(
+            reward_pool,
+            pool_escrow_account.clone(),
+            pool_escrow_account,
+            reward_escrow_account,
+            mint,
+            token_program,
+            payable,
+        )?;
+    }
+
+    // Compounded principal is staked at 1.0x like every liquid deposit
+    staking_pool.liquid_pending_rewards = pending - payable;
+    staking_pool.total_staked = staking_pool
+        .total_staked
+        .checked_add(payable)
+        .ok_or(StakingError::MathOverflow)?;
+    staking_pool.total_weight = staking_pool
+        .total_weight
+        .checked_add(payable as u128)
+        .ok_or(StakingError::MathOverflow)?;
+    staking_pool.liquid_reward_debt = staking_pool
+        .total_weight
+        .saturating_mul(staking_pool.acc_reward_per_weight)
+        .checked_div(ACC_PRECISION)
+        .unwrap_or(0);
+
+    Ok(payable)
+}
+
+fn process_stake(ctx: Context<Stake>, position_id: u64, amount: u64, tier_index: u8) -> Result<()> {
+    let tier = ctx.accounts.lock_tiers.get_enabled(tier_index)?;
+    let lock_duration = tier.lock_duration;
+    let multiplier_bps = tier.multiplier_bps;
+
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    let stake_index = &mut ctx.accounts.stake_index;
+    let user_staking_account = &mut ctx.accounts.user_staking_account;
+
+    let now = Clock::get()?.unix_timestamp;
+    staking_pool.update_pool(now)?;
+
+    // First position in this pool: set up the index
+    stake_index.init_if_new(
+        ctx.accounts.beneficiary.key(),
+        staking_pool.key(),
+        ctx.bumps.stake_index,
+    );
+    stake_index.open(position_id)?;
+
+    let new_weight = (amount as u128)
+        .saturating_mul(multiplier_bps as u128)
+        .checked_div(BPS_DENOMINATOR as u128)
+        .ok_or(StakingError::MathOverflow)?;
+
+    user_staking_account.owner = ctx.accounts.beneficiary.key();
+    user_staking_account.funder = ctx.accounts.user.key();
+    user_staking_account.position_id = position_id;
+    user_staking_account.staked_amount = amount;
+    user_staking_account.stake_timestamp = now;
+    user_staking_account.lock_duration = lock_duration;
+    user_staking_account.lock_tier = tier_index;
+    user_staking_account.multiplier_bps = multiplier_bps;
+    user_staking_account.weight = new_weight;
+    user_staking_account.weight_checkpoints = Vec::new();
+    user_staking_account.record_weight_checkpoint(staking_pool.epoch_index);
+    user_staking_account.pending_rewards = 0;
+    user_staking_account.secondary_rewards = Vec::new();
+    user_staking_account.reward_debt = new_weight
+        .saturating_mul(staking_pool.acc_reward_per_weight)
+        .checked_div(ACC_PRECISION)
+        .unwrap_or(0);
+    user_staking_account.sync_secondary_debts(staking_pool);
+
+    staking_pool.total_staked = staking_pool
+        .total_staked
+        .checked_add(amount)
+        .ok_or(StakingError::MathOverflow)?;
+    staking_pool.total_weight = staking_pool
+        .total_weight
+        .checked_add(new_weight)
+        .ok_or(StakingError::MathOverflow)?;
+
+    // Rewards carried over from an empty pool go to the stakers now present
+    staking_pool.fold_undistributed_rewards(now)?;
+
+    match staking_pool.token_type {
+        TokenType::SOL => {
+            // ✅ Verify the pool_escrow_account is the correct SOL vault PDA
+            let staking_pool_key = staking_pool.key();
+            let sol_vault_seeds = &[SEED_SOL_VAULT, staking_pool_key.as_ref()];
+            let (sol_vault_pda, _bump) =
+                Pubkey::find_program_address(sol_vault_seeds, ctx.program_id);
+
+            require!(
+                ctx.accounts.pool_escrow_account.key() == sol_vault_pda,
+                StakingError::InvalidEscrowAccount
+            );
+
+            // Transfer SOL to vault using System Program
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.user.to_account_info(),
+                        to: ctx.accounts.pool_escrow_account.to_account_info(),
+                    },
+                ),
+                amount,
+            )?;
+
+            msg!("✅ {} lamports SOL staked to vault", amount);
+            msg!("   From: {}", ctx.accounts.user.key());
+            msg!("   To SOL Vault: {}", sol_vault_pda);
+        }
+        TokenType::SPL => {
+            let mint_data = ctx.accounts.mint.try_borrow_data()?;
+            let mint = Mint::try_deserialize(&mut &mint_data[..])?;
+            let mint_decimals = mint.decimals;
+
+            token_2022::transfer_checked(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.user_token_account.to_account_info(),
+                        to: ctx.accounts.pool_escrow_account.to_account_info(),
+                        mint: ctx.accounts.mint.to_account_info(),
+                        authority: ctx.accounts.user.to_account_info(),
+                    },
+                ),
+                amount,
+                mint_decimals,
+            )?;
+
+            msg!("✅ {} SPL tokens staked", amount);
+        }
+    }
+
+    msg!(
+        "✅ {} tokens staked for user: {} (funded by {}) in position {} for {} seconds (weight: {}, multiplier: {}x)",
+        amount,
+        ctx.accounts.beneficiary.key(),
+        ctx.accounts.user.key(),
+        position_id,
+        lock_duration,
+        new_weight,
+        multiplier_bps as f64 / BPS_DENOMINATOR as f64
+    );
+
+    Ok(())
+}
+
+fn process_unstake(ctx: Context<Unstake>, amount: u64, early: bool) -> Result<()> {
+    let user_staking_account = &mut ctx.accounts.user_staking_account;
+    let staking_pool = &mut ctx.accounts.staking_pool;
+
+    // Pools with a cooldown only release stake through the unbonding queue,
+    // which also rules out early unstake there
+    require!(
+        staking_pool.unbonding_cooldown == 0,
+        StakingError::UnbondingRequired
+    );
+
+    let current_time = Clock::get()?.unix_timestamp;
+    staking_pool.update_pool(current_time)?;
+
+    require!(
+        amount > 0 && amount <= user_staking_account.staked_amount,
+        StakingError::InsufficientStakedBalance
+    );
+
+    let amount_in_base_units = amount;
+
+    // Weight leaves the pool in proportion to the withdrawn amount
+    let weight_removed: u128 = if amount == user_staking_account.staked_amount {
+        user_staking_account.weight
+    } else {
+        user_staking_account
+            .weight
+            .saturating_mul(amount as u128)
+            .checked_div(user_staking_account.staked_amount as u128)
+            .ok_or(StakingError::MathOverflow)?
+    };
+
+    // Lock must have expired unless the user opts into the early unstake penalty
+    let lock_end = user_staking_account
+        .stake_timestamp
+        .saturating_add(user_staking_account.lock_duration);
+    let penalty_bps = if current_time < lock_end {
+        require!(early, StakingError::StakeLockActive);
+        require!(
+            staking_pool.early_unstake_penalty_bps > 0,
+            StakingError::EarlyUnstakeDisabled
+        );
+        staking_pool.early_unstake_penalty_bps
     } else {
-        MULTIPLIER_1M_BPS
+        0
+    };
+    let penalty_amount = (amount_in_base_units as u128)
+        .saturating_mul(penalty_bps as u128)
+        .checked_div(BPS_DENOMINATOR as u128)
+        .ok_or(StakingError::MathOverflow)? as u64;
+    let payout_amount = amount_in_base_units
+        .checked_sub(penalty_amount)
+        .ok_or(StakingError::MathOverflow)?;
+
+    let accumulated_per_user: u128 = user_staking_account
+        .weight
+        .saturating_mul(staking_pool.acc_reward_per_weight)
+        .checked_div(ACC_PRECISION)
+        .unwrap_or(0);
+    let pending_now: u128 =
+        accumulated_per_user.saturating_sub(user_staking_account.reward_debt);
+    if pending_now > 0 {
+        let add: u64 = pending_now.min(u128::from(u64::MAX)) as u64;
+        user_staking_account.pending_rewards =
+            user_staking_account.pending_rewards.saturating_add(add);
+    }
+    user_staking_account.settle_secondary_rewards(staking_pool);
+
+    match staking_pool.token_type {
+        TokenType::SOL => {
+            // ✅ Verify the pool_escrow_account is the correct SOL vault PDA
+            let staking_pool_key = staking_pool.key();
+            let sol_vault_seeds = &[SEED_SOL_VAULT, staking_pool_key.as_ref()];
+            let (sol_vault_pda, sol_vault_bump) =
+                Pubkey::find_program_address(sol_vault_seeds, ctx.program_id);
+
+            require!(
+                ctx.accounts.pool_escrow_account.key() == sol_vault_pda,
+                StakingError::InvalidEscrowAccount
+            );
+
+            // ✅ Use System Program transfer with PDA signer (not direct lamport manipulation)
+            let vault_signer_seeds =
+                &[SEED_SOL_VAULT, staking_pool_key.as_ref(), &[sol_vault_bump]];
+            let signer_seeds: &[&[&[u8]]] = &[vault_signer_seeds];
+
+            system_program::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.pool_escrow_account.to_account_info(),
+                        to: ctx.accounts.user.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                payout_amount,
+            )?;
+
+            // Early unstake penalty is routed to the reward pool for remaining stakers
+            if penalty_amount > 0 {
+                system_program::transfer(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: ctx.accounts.pool_escrow_account.to_account_info(),
+                            to: ctx.accounts.reward_pool.to_account_info(),
+                        },
+                        signer_seeds,
+                    ),
+                    penalty_amount,
+                )?;
+                ctx.accounts.reward_pool.total_funds = ctx
+                    .accounts
+                    .reward_pool
+                    .total_funds
+                    .checked_add(penalty_amount)
+                    .ok_or(StakingError::MathOverflow)?;
+
+                msg!(
+                    "⚠️ Early unstake penalty: {} lamports SOL sent to reward pool",
+                    penalty_amount
+                );
+            }
+
+            msg!(
+                "✅ {} lamports SOL unstaked from vault",
+                payout_amount
+            );
+            msg!("   From SOL Vault: {}", sol_vault_pda);
+            msg!("   To: {}", ctx.accounts.user.key());
+        }
+        TokenType::SPL => {
+            // Release the mint borrow before CPIs that may write to the mint (burn)
+            let mint_decimals = {
+                let mint_data = ctx.accounts.mint.try_borrow_data()?;
+                let mint = Mint::try_deserialize(&mut &mint_data[..])?;
+                mint.decimals
+            };
+
+            let staking_pool_admin = staking_pool.admin;
+            let staking_pool_seed_mint = staking_pool.seed_mint();
+            let token_type_seed = [staking_pool.token_type as u8];
+            let staking_pool_bump = staking_pool.bump;
+            let staking_pool_seeds = &[
+                SEED_STAKING_POOL,
+                staking_pool_admin.as_ref(),
+                staking_pool_seed_mint.as_ref(),
+                token_type_seed.as_ref(),
+                &[staking_pool_bump],
+            ];
+            let signer_seeds: &[&[&[u8]]] = &[staking_pool_seeds];
+
+            token_2022::transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.pool_escrow_account.to_account_info(),
+                        to: ctx.accounts.user_token_account.to_account_info(),
+                        mint: ctx.accounts.mint.to_account_info(),
+                        authority: staking_pool.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                payout_amount,
+                mint_decimals,
+            )?;
+
+            // Early unstake penalty is burned from the staking escrow
+            if penalty_amount > 0 {
+                token_2022::burn(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_program.to_account_info(),
+                        Burn {
+                            mint: ctx.accounts.mint.to_account_info(),
+                            from: ctx.accounts.pool_escrow_account.to_account_info(),
+                            authority: staking_pool.to_account_info(),
+                        },
+                        signer_seeds,
+                    ),
+                    penalty_amount,
+                )?;
+
+                msg!("⚠️ Early unstake penalty: {} SPL tokens burned", penalty_amount);
+            }
+
+            msg!("✅ {} SPL tokens unstaked", payout_amount);
+        }
+    }
+
+    user_staking_account.staked_amount = user_staking_account
+        .staked_amount
+        .checked_sub(amount_in_base_units)
+        .ok_or(StakingError::MathOverflow)?;
+    staking_pool.total_staked = staking_pool
+        .total_staked
+        .checked_sub(amount_in_base_units)
+        .ok_or(StakingError::MathOverflow)?;
+
+    staking_pool.total_weight = staking_pool.total_weight.saturating_sub(weight_removed);
+    user_staking_account.weight = user_staking_account.weight.saturating_sub(weight_removed);
+    user_staking_account.record_weight_checkpoint(staking_pool.epoch_index);
+    user_staking_account.reward_debt = user_staking_account
+        .weight
+        .saturating_mul(staking_pool.acc_reward_per_weight)
+        .checked_div(ACC_PRECISION)
+        .unwrap_or(0);
+    user_staking_account.sync_secondary_debts(staking_pool);
+
+    // Credit the SOL penalty to the stakers that remain in the pool
+    if penalty_amount > 0 && staking_pool.token_type == TokenType::SOL {
+        staking_pool.credit_rewards(penalty_amount, current_time)?;
+    }
+
+    // Pay out accrued rewards now; anything the reward pool cannot cover
+    // stays in pending_rewards as a residual claim
+    let payable = user_staking_account
+        .pending_rewards
+        .min(ctx.accounts.reward_pool.total_funds);
+    if payable > 0 {
+        pay_rewards(
+            &mut ctx.accounts.reward_pool,
+            ctx.accounts.user.to_account_info(),
+            ctx.accounts.user_reward_token_account.to_account_info(),
+            ctx.accounts.reward_escrow_account.to_account_info(),
+            ctx.accounts.reward_mint.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            payable,
+        )?;
+        user_staking_account.pending_rewards -= payable;
+    }
+
+    msg!(
+        "✅ User {} unstaked {} tokens ({} remaining). Rewards paid: {}, still pending: {}",
+        ctx.accounts.user.key(),
+        amount_in_base_units,
+        user_staking_account.staked_amount,
+        payable,
+        user_staking_account.pending_rewards
+    );
+
+    // Close the position once it is fully withdrawn and all rewards are paid
+    if user_staking_account.staked_amount == 0
+        && user_staking_account.pending_rewards == 0
+        && !user_staking_account.has_pending_secondary_rewards()
+    {
+        ctx.accounts
+            .stake_index
+            .remove(user_staking_account.position_id);
+        user_staking_account.close(ctx.accounts.user.to_account_info())?;
+    }
+
+    Ok(())
+}
+
+/// Change the timelock delay; a longer delay also pushes back any queued change
+fn set_config_change_delay(config: &mut PlatformConfig, config_change_delay: i64) -> Result<()> {
+    require!(config_change_delay >= 0, PlatformError::InvalidConfigChangeDelay);
+
+    if config_change_delay >= config.config_change_delay {
+        if config.queued_config_eta != 0 {
+            config.queued_config_eta = config
+                .queued_config_eta
+                .checked_add(config_change_delay - config.config_change_delay)
+                .ok_or(PlatformError::MathOverflow)?;
+            // Keep the queued change from restoring the shorter delay
+            config.queued_config_change_delay = config_change_delay;
+        }
+        config.config_change_delay = config_change_delay;
+        msg!("✅ Config change delay updated to {} seconds", config_change_delay);
+        return Ok(());
     }
+
+    let developer_share_bps = config.developer_share_bps;
+    let platform_share_bps = config.platform_share_bps;
+    queue_config_change(
+        config,
+        developer_share_bps,
+        platform_share_bps,
+        config_change_delay,
+    )
+}
+
+/// Drop the queued config change
+fn cancel_queued_config(config: &mut PlatformConfig) -> Result<()> {
+    require!(
+        config.queued_config_eta != 0,
+        PlatformError::NoQueuedConfigChange
+    );
+
+    config.queued_developer_share_bps = 0;
+    config.queued_platform_share_bps = 0;
+    config.queued_config_change_delay = 0;
+    config.queued_config_eta = 0;
+
+    msg!("✅ Queued config change cancelled");
+
+    Ok(())
+}
+
+/// Drop the platform wallet awaiting acceptance
+fn cancel_pending_platform_wallet(config: &mut PlatformConfig) -> Result<()> {
+    require!(
+        config.pending_platform_wallet != Pubkey::default(),
+        PlatformError::NoPendingPlatformWallet
+    );
+
+    msg!(
+        "✅ Platform wallet proposal cancelled: {}",
+        config.pending_platform_wallet
+    );
+    config.pending_platform_wallet = Pubkey::default();
+
+    Ok(())
 }
 
-fn validate_lock_duration(lock_duration: i64) -> Result<()> {
+/// Queue a platform config change behind the current timelock delay
+fn queue_config_change(
+    config: &mut PlatformConfig,
+    developer_share_bps: u16,
+    platform_share_bps: u16,
+    config_change_delay: i64,
+) -> Result<()> {
     require!(
-        lock_duration == ONE_MONTH
-            || lock_duration == THREE_MONTHS
-            || lock_duration == SIX_MONTHS
-            || lock_duration == TWELVE_MONTHS,
-        StakingError::InvalidLockDuration
+        config.queued_config_eta == 0,
+        PlatformError::ConfigChangeAlreadyQueued
+    );
+
+    let eta = Clock::get()?
+        .unix_timestamp
+        .checked_add(config.config_change_delay)
+        .ok_or(PlatformError::MathOverflow)?;
+
+    config.queued_developer_share_bps = developer_share_bps;
+    config.queued_platform_share_bps = platform_share_bps;
+    config.queued_config_change_delay = config_change_delay;
+    config.queued_config_eta = eta;
+
+    msg!(
+        "⏳ Config change queued: {}% developer, {}% platform, delay {}s (executable at {})",
+        developer_share_bps / 100,
+        platform_share_bps / 100,
+        config_change_delay,
+        eta
     );
+
     Ok(())
 }
 
@@ -117,12 +893,14 @@ pub mod multiversed_dapp {
         developer_share_bps: u16,
         platform_share_bps: u16,
         developer_onboarding_fee: u64,
+        config_change_delay: i64,
     ) -> Result<()> {
         // Validate shares sum to 100%
         require!(
             developer_share_bps + platform_share_bps == BPS_DENOMINATOR as u16,
             PlatformError::InvalidSharePercentages
         );
+        require!(config_change_delay >= 0, PlatformError::InvalidConfigChangeDelay);
 
         let config = &mut ctx.accounts.platform_config;
         
@@ -135,6 +913,21 @@ pub mod multiversed_dapp {
         config.platform_share_bps = platform_share_bps;
         config.developer_onboarding_fee = developer_onboarding_fee;
         config.onboarding_fee_enabled = true;
+        config.pending_super_admin = Pubkey::default();
+        config.pending_platform_wallet = Pubkey::default();
+        config.admin_council = Pubkey::default();
+        config.config_change_delay = config_change_delay;
+        config.distribution_bounds = DistributionBounds::UNRESTRICTED;
+        config.paused_operations = 0;
+        config.queued_developer_share_bps = 0;
+        config.queued_platform_share_bps = 0;
+        config.queued_config_change_delay = 0;
+        config.queued_config_eta = 0;
+        config.governance_staking_pool = Pubkey::default();
+        config.governance_quorum_bps = 0;
+        config.governance_voting_period = 0;
+        config.governance_proposal_count = 0;
+        config.pool_bindings = Vec::new();
         config.is_initialized = true;
         config.bump = ctx.bumps.platform_config;
 
@@ -142,12 +935,15 @@ pub mod multiversed_dapp {
         msg!("   Developer share: {}%", developer_share_bps / 100);
         msg!("   Platform share: {}%", platform_share_bps / 100);
         msg!("   Developer onboarding fee: {} lamports", developer_onboarding_fee);
+        msg!("   Config change delay: {} seconds", config_change_delay);
 
         Ok(())
     }
 
     /// Update platform configuration (super admin only)
-    /// Allows adjusting the revenue share percentages
+    /// Queues the new revenue share percentages behind the config change delay;
+    /// apply with `execute_queued_config_change` once the delay has passed
+    /// Once governance is configured, shares only change through governance proposals
     pub fn update_platform_config(
         ctx: Context<UpdatePlatformConfig>,
         developer_share_bps: u16,
@@ -160,72 +956,238 @@ pub mod multiversed_dapp {
         );
 
         let config = &mut ctx.accounts.platform_config;
-        config.developer_share_bps = developer_share_bps;
-        config.platform_share_bps = platform_share_bps;
+        let config_change_delay = config.config_change_delay;
+        queue_config_change(
+            config,
+            developer_share_bps,
+            platform_share_bps,
+            config_change_delay,
+        )
+    }
+
+    /// Update the allowed range for each tournament distribution bucket (fee manager role)
+    /// Applies to tournaments created after the update
+    /// Once governance is configured, bounds only change through governance proposals
+    pub fn update_distribution_bounds(
+        ctx: Context<UpdateDistributionBounds>,
+        distribution_bounds: DistributionBounds,
+    ) -> Result<()> {
+        require!(
+            distribution_bounds.is_valid(),
+            PlatformError::InvalidDistributionBounds
+        );
+
+        let config = &mut ctx.accounts.platform_config;
+        config.distribution_bounds = distribution_bounds;
+
+        msg!(
+            "✅ Distribution bounds updated - Prize: {}-{}%, Revenue: {}-{}%, Staking: {}-{}%, Burn: {}-{}%",
+            distribution_bounds.min_prize_percentage,
+            distribution_bounds.max_prize_percentage,
+            distribution_bounds.min_revenue_percentage,
+            distribution_bounds.max_revenue_percentage,
+            distribution_bounds.min_staking_percentage,
+            distribution_bounds.max_staking_percentage,
+            distribution_bounds.min_burn_percentage,
+            distribution_bounds.max_burn_percentage
+        );
+
+        Ok(())
+    }
+
+    /// Set the emergency pause bitmask (super admin or ROLE_PAUSER)
+    /// Use PAUSE_WITHDRAW_ONLY to halt everything except unstake and claim
+    pub fn set_pause_flags(ctx: Context<SetPauseFlags>, paused_operations: u16) -> Result<()> {
+        require!(
+            paused_operations & !PAUSE_ALL == 0,
+            PlatformError::InvalidPauseFlags
+        );
+
+        let config = &mut ctx.accounts.platform_config;
+        config.paused_operations = paused_operations;
 
         msg!(
-            "✅ Platform config updated: {}% developer, {}% platform",
-            developer_share_bps / 100,
-            platform_share_bps / 100
+            "✅ Pause flags set to {:#07b} by {}",
+            paused_operations,
+            ctx.accounts.authority.key()
         );
 
         Ok(())
     }
 
-    /// Update platform wallet (super admin only)
-    pub fn update_platform_wallet(
-        ctx: Context<UpdatePlatformWallet>,
+    /// Update the config change delay (super admin only)
+    /// Longer delays apply immediately; shorter delays are queued behind the current one
+    /// Once a council is configured this goes through a council proposal instead
+    pub fn update_config_change_delay(
+        ctx: Context<UpdateConfigChangeDelay>,
+        config_change_delay: i64,
+    ) -> Result<()> {
+        set_config_change_delay(&mut ctx.accounts.platform_config, config_change_delay)
+    }
+
+    /// Apply a queued config change once its delay has elapsed
+    /// PERMISSIONLESS: Anyone can execute a matured change
+    pub fn execute_queued_config_change(
+        ctx: Context<ExecuteQueuedConfigChange>,
     ) -> Result<()> {
         let config = &mut ctx.accounts.platform_config;
-        config.platform_wallet = ctx.accounts.new_platform_wallet.key();
 
-        msg!("✅ Platform wallet updated to: {}", config.platform_wallet);
+        require!(
+            config.queued_config_eta != 0,
+            PlatformError::NoQueuedConfigChange
+        );
+        require!(
+            Clock::get()?.unix_timestamp >= config.queued_config_eta,
+            PlatformError::ConfigChangeNotReady
+        );
+
+        config.developer_share_bps = config.queued_developer_share_bps;
+        config.platform_share_bps = config.queued_platform_share_bps;
+        config.config_change_delay = config.queued_config_change_delay;
+        config.queued_developer_share_bps = 0;
+        config.queued_platform_share_bps = 0;
+        config.queued_config_change_delay = 0;
+        config.queued_config_eta = 0;
+
+        msg!(
+            "✅ Platform config updated: {}% developer, {}% platform, delay {}s",
+            config.developer_share_bps / 100,
+            config.platform_share_bps / 100,
+            config.config_change_delay
+        );
 
         Ok(())
     }
 
-    /// Transfer super admin role (super admin only)
-    pub fn transfer_super_admin(
-        ctx: Context<TransferSuperAdmin>,
+    /// Cancel a queued config change (fee manager role)
+    /// Once a council is configured this goes through a council proposal instead
+    pub fn cancel_queued_config_change(
+        ctx: Context<CancelQueuedConfigChange>,
+    ) -> Result<()> {
+        cancel_queued_config(&mut ctx.accounts.platform_config)
+    }
+
+    /// Propose a new platform wallet (super admin only)
+    /// The wallet only changes once the proposed wallet accepts
+    pub fn propose_platform_wallet(
+        ctx: Context<ProposePlatformWallet>,
     ) -> Result<()> {
+        let new_platform_wallet = ctx.accounts.new_platform_wallet.key();
+        require!(
+            new_platform_wallet != Pubkey::default(),
+            PlatformError::InvalidPlatformWallet
+        );
+
         let config = &mut ctx.accounts.platform_config;
-        config.super_admin = ctx.accounts.new_super_admin.key();
+        config.pending_platform_wallet = new_platform_wallet;
 
-        msg!("✅ Super admin transferred to: {}", config.super_admin);
+        msg!("✅ Platform wallet change proposed: {}", new_platform_wallet);
 
         Ok(())
     }
 
-    /// Update developer onboarding fee configuration (super admin only)
-    pub fn update_developer_onboarding_fee(
-        ctx: Context<UpdateDeveloperOnboardingFee>,
-        developer_onboarding_fee: u64,
-        onboarding_fee_enabled: bool,
+    /// Accept a pending platform wallet change (proposed wallet only)
+    pub fn accept_platform_wallet(
+        ctx: Context<AcceptPlatformWallet>,
     ) -> Result<()> {
         let config = &mut ctx.accounts.platform_config;
+        config.platform_wallet = config.pending_platform_wallet;
+        config.pending_platform_wallet = Pubkey::default();
 
-        config.developer_onboarding_fee = developer_onboarding_fee;
-        config.onboarding_fee_enabled = onboarding_fee_enabled;
+        msg!("✅ Platform wallet updated to: {}", config.platform_wallet);
 
-        msg!("✅ Developer onboarding fee updated");
-        msg!("   Fee: {} lamports ({} SOL)", 
-            developer_onboarding_fee,
-            developer_onboarding_fee as f64 / 1_000_000_000.0
+        Ok(())
+    }
+
+    /// Cancel a pending platform wallet change (treasury role)
+    /// Once a council is configured this goes through a council proposal instead
+    pub fn cancel_platform_wallet_proposal(
+        ctx: Context<CancelPlatformWalletProposal>,
+    ) -> Result<()> {
+        cancel_pending_platform_wallet(&mut ctx.accounts.platform_config)
+    }
+
+    /// Propose a new super admin (super admin only)
+    /// The role only moves once the proposed key signs `accept_super_admin`
+    pub fn propose_super_admin(
+        ctx: Context<ProposeSuperAdmin>,
+    ) -> Result<()> {
+        let new_super_admin = ctx.accounts.new_super_admin.key();
+        require!(
+            new_super_admin != Pubkey::default(),
+            PlatformError::InvalidPendingSuperAdmin
         );
-        msg!("   Fees enabled: {}", onboarding_fee_enabled);
+
+        let config = &mut ctx.accounts.platform_config;
+        config.pending_super_admin = new_super_admin;
+
+        msg!("✅ Super admin handover proposed to: {}", new_super_admin);
 
         Ok(())
     }
 
-    /// Pay developer onboarding fee - called during developer onboarding
-    /// Transfers SOL from developer to platform wallet
-    pub fn pay_developer_onboarding_fee(
-        ctx: Context<PayDeveloperOnboardingFee>,
+    /// Accept a pending super admin handover (proposed super admin only)
+    pub fn accept_super_admin(
+        ctx: Context<AcceptSuperAdmin>,
     ) -> Result<()> {
-        let platform_config = &ctx.accounts.platform_config;
-        let onboarding_record = &mut ctx.accounts.onboarding_record;
+        let config = &mut ctx.accounts.platform_config;
+        config.super_admin = config.pending_super_admin;
+        config.pending_super_admin = Pubkey::default();
 
-        // Check if fees are enabled
+        msg!("✅ Super admin transferred to: {}", config.super_admin);
+
+        Ok(())
+    }
+
+    /// Cancel a pending super admin handover (super admin only)
+    pub fn cancel_super_admin_proposal(
+        ctx: Context<CancelSuperAdminProposal>,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.platform_config;
+        require!(
+            config.pending_super_admin != Pubkey::default(),
+            PlatformError::NoPendingSuperAdmin
+        );
+
+        msg!(
+            "✅ Super admin handover cancelled: {}",
+            config.pending_super_admin
+        );
+        config.pending_super_admin = Pubkey::default();
+
+        Ok(())
+    }
+
+    /// Update developer onboarding fee configuration (super admin only)
+    pub fn update_developer_onboarding_fee(
+        ctx: Context<UpdateDeveloperOnboardingFee>,
+        developer_onboarding_fee: u64,
+        onboarding_fee_enabled: bool,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.platform_config;
+
+        config.developer_onboarding_fee = developer_onboarding_fee;
+        config.onboarding_fee_enabled = onboarding_fee_enabled;
+
+        msg!("✅ Developer onboarding fee updated");
+        msg!("   Fee: {} lamports ({} SOL)", 
+            developer_onboarding_fee,
+            developer_onboarding_fee as f64 / 1_000_000_000.0
+        );
+        msg!("   Fees enabled: {}", onboarding_fee_enabled);
+
+        Ok(())
+    }
+
+    /// Pay developer onboarding fee - called during developer onboarding
+    /// Transfers SOL from developer to platform wallet
+    pub fn pay_developer_onboarding_fee(
+        ctx: Context<PayDeveloperOnboardingFee>,
+    ) -> Result<()> {
+        let platform_config = &ctx.accounts.platform_config;
+        let onboarding_record = &mut ctx.accounts.onboarding_record;
+
+        // Check if fees are enabled
         if !platform_config.onboarding_fee_enabled {
             // Record onboarding even if fees disabled (fee_paid = 0)
             onboarding_record.developer = ctx.accounts.developer.key();
@@ -271,7 +1233,7 @@ pub mod multiversed_dapp {
 
     /// Close/flush a developer's onboarding record
     /// This allows the developer to re-onboard and pay the fee again
-    /// Only callable by super_admin
+    /// Only callable by super_admin or ROLE_DEVELOPER_MODERATOR
     /// Rent is returned to the admin (or optionally to the developer)
     pub fn close_developer_onboarding_record(
         ctx: Context<CloseDeveloperOnboardingRecord>,
@@ -282,7 +1244,7 @@ pub mod multiversed_dapp {
         msg!("   Developer: {}", onboarding_record.developer);
         msg!("   Original Fee Paid: {} lamports", onboarding_record.fee_paid);
         msg!("   Original Timestamp: {}", onboarding_record.timestamp);
-        msg!("   Closed By: {}", ctx.accounts.super_admin.key());
+        msg!("   Closed By: {}", ctx.accounts.authority.key());
         msg!("   Rent returned to: {}", ctx.accounts.rent_recipient.key());
 
         // Account closure is handled by Anchor's `close` constraint
@@ -291,6 +1253,580 @@ pub mod multiversed_dapp {
         Ok(())
     }
 
+    // ==============================
+    // ROLE REGISTRY
+    // ==============================
+
+    /// Grant one or more ROLE_* bits to a key (super admin only)
+    pub fn grant_role(ctx: Context<GrantRole>, holder: Pubkey, roles: u8) -> Result<()> {
+        require!(
+            roles != 0 && roles & !ALL_ROLES == 0,
+            PlatformError::InvalidRoles
+        );
+
+        let role_account = &mut ctx.accounts.role_account;
+        role_account.holder = holder;
+        role_account.roles |= roles;
+        role_account.granted_by = ctx.accounts.super_admin.key();
+        role_account.updated_at = Clock::get()?.unix_timestamp;
+        role_account.bump = ctx.bumps.role_account;
+
+        msg!(
+            "✅ Roles {:#06b} granted to {} (now {:#06b})",
+            roles,
+            holder,
+            role_account.roles
+        );
+
+        Ok(())
+    }
+
+    /// Revoke one or more ROLE_* bits from a key (super admin only)
+    /// The role account is closed once no roles remain
+    pub fn revoke_role(ctx: Context<RevokeRole>, holder: Pubkey, roles: u8) -> Result<()> {
+        require!(
+            roles != 0 && roles & !ALL_ROLES == 0,
+            PlatformError::InvalidRoles
+        );
+
+        let role_account = &mut ctx.accounts.role_account;
+        role_account.roles &= !roles;
+        role_account.granted_by = ctx.accounts.super_admin.key();
+        role_account.updated_at = Clock::get()?.unix_timestamp;
+
+        msg!(
+            "✅ Roles {:#06b} revoked from {} (now {:#06b})",
+            roles,
+            holder,
+            role_account.roles
+        );
+
+        if role_account.roles == 0 {
+            role_account.close(ctx.accounts.super_admin.to_account_info())?;
+            msg!("   Role account closed");
+        }
+
+        Ok(())
+    }
+
+    // ==============================
+    // ADMIN COUNCIL
+    // ==============================
+
+    /// Initialize the M-of-N admin council (super admin only, one-time)
+    /// Once set, platform config, wallet and onboarding fee changes
+    /// can only be made through executed council proposals
+    /// Members and threshold are changed later through council proposals as well
+    pub fn initialize_admin_council(
+        ctx: Context<InitializeAdminCouncil>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        require!(
+            !members.is_empty() && members.len() <= MAX_COUNCIL_MEMBERS,
+            CouncilError::InvalidMembers
+        );
+        for (i, member) in members.iter().enumerate() {
+            require!(
+                *member != Pubkey::default() && !members[..i].contains(member),
+                CouncilError::InvalidMembers
+            );
+        }
+        require!(
+            threshold > 0 && threshold as usize <= members.len(),
+            CouncilError::InvalidThreshold
+        );
+
+        let council = &mut ctx.accounts.admin_council;
+        council.members = members;
+        council.threshold = threshold;
+        council.proposal_count = 0;
+        council.membership_version = 0;
+        council.bump = ctx.bumps.admin_council;
+
+        let config = &mut ctx.accounts.platform_config;
+        config.admin_council = council.key();
+
+        msg!("✅ Admin council initialized");
+        msg!("   Members: {}", council.members.len());
+        msg!("   Threshold: {}", threshold);
+
+        Ok(())
+    }
+
+    /// Create a council proposal holding a pending platform config action
+    /// The proposer's approval is recorded immediately
+    pub fn create_council_proposal(
+        ctx: Context<CreateCouncilProposal>,
+        action: CouncilAction,
+    ) -> Result<()> {
+        if let CouncilAction::UpdatePlatformConfig {
+            developer_share_bps,
+            platform_share_bps,
+        } = action
+        {
+            require!(
+                developer_share_bps as u64 + platform_share_bps as u64 == BPS_DENOMINATOR,
+                PlatformError::InvalidSharePercentages
+            );
+        }
+        if let CouncilAction::UpdateConfigChangeDelay {
+            config_change_delay,
+        } = action
+        {
+            require!(config_change_delay >= 0, PlatformError::InvalidConfigChangeDelay);
+        }
+
+        let council = &mut ctx.accounts.admin_council;
+        council.validate_membership_action(&action)?;
+        let proposal = &mut ctx.accounts.council_proposal;
+        let proposer = ctx.accounts.proposer.key();
+
+        let member_index = council
+            .member_index(&proposer)
+            .ok_or(CouncilError::NotCouncilMember)?;
+
+        proposal.council = council.key();
+        proposal.proposal_id = council.proposal_count;
+        proposal.proposer = proposer;
+        proposal.action = action;
+        proposal.approvals = 1u16 << member_index;
+        proposal.approval_count = 1;
+        proposal.executed = false;
+        proposal.created_at = Clock::get()?.unix_timestamp;
+        proposal.membership_version = council.membership_version;
+        proposal.bump = ctx.bumps.council_proposal;
+
+        council.proposal_count = council
+            .proposal_count
+            .checked_add(1)
+            .ok_or(CouncilError::MathOverflow)?;
+
+        msg!(
+            "✅ Council proposal {} created by {}: {:?}",
+            proposal.proposal_id,
+            proposer,
+            proposal.action
+        );
+
+        Ok(())
+    }
+
+    /// Approve a pending council proposal (council members only)
+    /// Proposals expire after COUNCIL_PROPOSAL_EXPIRY and are voided by membership changes
+    pub fn approve_council_proposal(
+        ctx: Context<ApproveCouncilProposal>,
+        _proposal_id: u64,
+    ) -> Result<()> {
+        let council = &ctx.accounts.admin_council;
+        let proposal = &mut ctx.accounts.council_proposal;
+        let member = ctx.accounts.member.key();
+
+        require!(!proposal.executed, CouncilError::ProposalAlreadyExecuted);
+        proposal.require_live(council, Clock::get()?.unix_timestamp)?;
+
+        let member_index = council
+            .member_index(&member)
+            .ok_or(CouncilError::NotCouncilMember)?;
+        let member_bit = 1u16 << member_index;
+        require!(
+            proposal.approvals & member_bit == 0,
+            CouncilError::AlreadyApproved
+        );
+
+        proposal.approvals |= member_bit;
+        proposal.approval_count = proposal
+            .approval_count
+            .checked_add(1)
+            .ok_or(CouncilError::MathOverflow)?;
+
+        msg!(
+            "✅ Council proposal {} approved by {} ({}/{})",
+            proposal.proposal_id,
+            member,
+            proposal.approval_count,
+            council.threshold
+        );
+
+        Ok(())
+    }
+
+    /// Execute a council proposal once the approval threshold is met
+    /// Callable by any council member within COUNCIL_PROPOSAL_EXPIRY of its creation
+    pub fn execute_council_proposal(
+        ctx: Context<ExecuteCouncilProposal>,
+        _proposal_id: u64,
+    ) -> Result<()> {
+        let council = &mut ctx.accounts.admin_council;
+        let proposal = &mut ctx.accounts.council_proposal;
+        let config = &mut ctx.accounts.platform_config;
+
+        require!(!proposal.executed, CouncilError::ProposalAlreadyExecuted);
+        proposal.require_live(council, Clock::get()?.unix_timestamp)?;
+        require!(
+            proposal.approval_count >= council.threshold,
+            CouncilError::ThresholdNotMet
+        );
+
+        match proposal.action {
+            CouncilAction::UpdatePlatformConfig {
+                developer_share_bps,
+                platform_share_bps,
+            } => {
+                require!(
+                    developer_share_bps as u64 + platform_share_bps as u64 == BPS_DENOMINATOR,
+                    PlatformError::InvalidSharePercentages
+                );
+                require!(
+                    config.governance_staking_pool == Pubkey::default(),
+                    PlatformError::GovernanceControlled
+                );
+                let config_change_delay = config.config_change_delay;
+                queue_config_change(
+                    config,
+                    developer_share_bps,
+                    platform_share_bps,
+                    config_change_delay,
+                )?;
+            }
+            CouncilAction::UpdatePlatformWallet {
+                new_platform_wallet,
+            } => {
+                require!(
+                    new_platform_wallet != Pubkey::default(),
+                    PlatformError::InvalidPlatformWallet
+                );
+                // The new wallet must still sign `accept_platform_wallet`
+                config.pending_platform_wallet = new_platform_wallet;
+
+                msg!("✅ Platform wallet change proposed: {}", new_platform_wallet);
+            }
+            CouncilAction::UpdateDeveloperOnboardingFee {
+                developer_onboarding_fee,
+                onboarding_fee_enabled,
+            } => {
+                config.developer_onboarding_fee = developer_onboarding_fee;
+                config.onboarding_fee_enabled = onboarding_fee_enabled;
+
+                msg!(
+                    "✅ Developer onboarding fee updated: {} lamports (enabled: {})",
+                    developer_onboarding_fee,
+                    onboarding_fee_enabled
+                );
+            }
+            CouncilAction::CancelPlatformWalletProposal => {
+                cancel_pending_platform_wallet(config)?;
+            }
+            CouncilAction::UpdateConfigChangeDelay {
+                config_change_delay,
+            } => {
+                set_config_change_delay(config, config_change_delay)?;
+            }
+            CouncilAction::CancelQueuedConfigChange => {
+                cancel_queued_config(config)?;
+            }
+            CouncilAction::AddCouncilMember { .. }
+            | CouncilAction::RemoveCouncilMember { .. }
+            | CouncilAction::UpdateCouncilThreshold { .. } => {
+                council.apply_membership_action(&proposal.action)?;
+            }
+        }
+
+        proposal.executed = true;
+
+        msg!(
+            "✅ Council proposal {} executed by {}",
+            proposal.proposal_id,
+            ctx.accounts.member.key()
+        );
+
+        Ok(())
+    }
+
+    // ==============================
+    // STAKE-WEIGHTED GOVERNANCE
+    // ==============================
+
+    /// Point governance at a staking pool and set quorum and voting period (super admin only)
+    pub fn configure_governance(
+        ctx: Context<ConfigureGovernance>,
+        quorum_bps: u16,
+        voting_period: i64,
+    ) -> Result<()> {
+        require!(
+            quorum_bps > 0 && quorum_bps as u64 <= BPS_DENOMINATOR,
+            GovernanceError::InvalidQuorum
+        );
+        require!(voting_period > 0, GovernanceError::InvalidVotingPeriod);
+
+        let config = &mut ctx.accounts.platform_config;
+        config.governance_staking_pool = ctx.accounts.staking_pool.key();
+        config.governance_quorum_bps = quorum_bps;
+        config.governance_voting_period = voting_period;
+
+        msg!("✅ Governance configured");
+        msg!("   Staking pool: {}", config.governance_staking_pool);
+        msg!("   Quorum: {}%", quorum_bps as f64 / 100.0);
+        msg!("   Voting period: {}s", voting_period);
+
+        Ok(())
+    }
+
+    /// Bind the canonical staking and reward pools for an asset (super admin only)
+    /// Tournament revenue for that asset can only be distributed into the bound pair
+    pub fn set_canonical_pools(
+        ctx: Context<SetCanonicalPools>,
+        token_type: TokenType,
+        mint: Pubkey,
+    ) -> Result<()> {
+        let staking_pool = &ctx.accounts.staking_pool;
+        let reward_pool = &ctx.accounts.reward_pool;
+
+        // Bindings are keyed by the asset the reward pool pays out
+        require!(
+            reward_pool.token_type == token_type,
+            PlatformError::InvalidPoolBinding
+        );
+        if token_type == TokenType::SPL {
+            require!(reward_pool.mint == mint, PlatformError::InvalidPoolBinding);
+        }
+
+        let binding = PoolBinding {
+            token_type,
+            mint: pool_seed_mint(token_type, &mint),
+            staking_pool: staking_pool.key(),
+            reward_pool: reward_pool.key(),
+        };
+
+        let config = &mut ctx.accounts.platform_config;
+        match config
+            .pool_bindings
+            .iter_mut()
+            .find(|existing| existing.token_type == token_type && existing.mint == binding.mint)
+        {
+            Some(existing) => *existing = binding,
+            None => {
+                require!(
+                    config.pool_bindings.len() < MAX_POOL_BINDINGS,
+                    PlatformError::TooManyPoolBindings
+                );
+                config.pool_bindings.push(binding);
+            }
+        }
+
+        msg!("✅ Canonical pools set");
+        msg!("   Token type: {:?}", token_type);
+        msg!("   Mint: {}", binding.mint);
+        msg!("   Staking pool: {}", binding.staking_pool);
+        msg!("   Reward pool: {}", binding.reward_pool);
+
+        Ok(())
+    }
+
+    /// Create a governance proposal (any holder of a staked position)
+    /// Votes use the weights of the last completed epoch snapshot
+    pub fn create_governance_proposal(
+        ctx: Context<CreateGovernanceProposal>,
+        _position_id: u64,
+        action: GovernanceAction,
+    ) -> Result<()> {
+        match action {
+            GovernanceAction::UpdateDistributionBounds { distribution_bounds } => {
+                require!(
+                    distribution_bounds.is_valid(),
+                    PlatformError::InvalidDistributionBounds
+                );
+            }
+            GovernanceAction::UpdateDeveloperShare { developer_share_bps } => {
+                require!(
+                    developer_share_bps as u64 <= BPS_DENOMINATOR,
+                    PlatformError::InvalidSharePercentages
+                );
+            }
+        }
+
+        let config = &mut ctx.accounts.platform_config;
+        let proposal = &mut ctx.accounts.governance_proposal;
+        let now = Clock::get()?.unix_timestamp;
+
+        proposal.proposal_id = config.governance_proposal_count;
+        proposal.proposer = ctx.accounts.proposer.key();
+        proposal.staking_pool = ctx.accounts.staking_pool.key();
+        proposal.action = action;
+        proposal.created_at = now;
+        proposal.voting_ends_at = now
+            .checked_add(config.governance_voting_period)
+            .ok_or(GovernanceError::MathOverflow)?;
+        proposal.snapshot_epoch = ctx.accounts.epoch_snapshot.epoch;
+        proposal.total_weight_snapshot = ctx.accounts.epoch_snapshot.total_weight;
+        proposal.quorum_bps = config.governance_quorum_bps;
+        proposal.votes_for = 0;
+        proposal.votes_against = 0;
+        proposal.executed = false;
+        proposal.cancelled = false;
+        proposal.bump = ctx.bumps.governance_proposal;
+
+        config.governance_proposal_count = config
+            .governance_proposal_count
+            .checked_add(1)
+            .ok_or(GovernanceError::MathOverflow)?;
+
+        msg!(
+            "✅ Governance proposal {} created by {}: {:?} (voting ends at {})",
+            proposal.proposal_id,
+            proposal.proposer,
+            proposal.action,
+            proposal.voting_ends_at
+        );
+
+        Ok(())
+    }
+
+    /// Vote on a governance proposal with one stake position
+    /// The position votes with its weight in the proposal's epoch snapshot
+    pub fn cast_vote(
+        ctx: Context<CastVote>,
+        _proposal_id: u64,
+        position_id: u64,
+        support: bool,
+    ) -> Result<()> {
+        let proposal = &mut ctx.accounts.governance_proposal;
+
+        require!(!proposal.cancelled, GovernanceError::ProposalCancelled);
+        require!(
+            Clock::get()?.unix_timestamp < proposal.voting_ends_at,
+            GovernanceError::VotingClosed
+        );
+        let weight = ctx
+            .accounts
+            .user_staking_account
+            .weight_at_epoch(proposal.snapshot_epoch)
+            .ok_or(GovernanceError::NoVotingWeight)?;
+        require!(weight > 0, GovernanceError::NoVotingWeight);
+
+        if support {
+            proposal.votes_for = proposal
+                .votes_for
+                .checked_add(weight)
+                .ok_or(GovernanceError::MathOverflow)?;
+        } else {
+            proposal.votes_against = proposal
+                .votes_against
+                .checked_add(weight)
+                .ok_or(GovernanceError::MathOverflow)?;
+        }
+
+        let vote_record = &mut ctx.accounts.vote_record;
+        vote_record.proposal = proposal.key();
+        vote_record.voter = ctx.accounts.voter.key();
+        vote_record.position_id = position_id;
+        vote_record.weight = weight;
+        vote_record.support = support;
+        vote_record.bump = ctx.bumps.vote_record;
+
+        msg!(
+            "✅ {} voted {} on proposal {} with weight {}",
+            ctx.accounts.voter.key(),
+            if support { "for" } else { "against" },
+            proposal.proposal_id,
+            weight
+        );
+
+        Ok(())
+    }
+
+    /// Apply a passed governance proposal once voting has ended
+    /// Must run within GOVERNANCE_EXECUTION_WINDOW of the vote closing, after which it expires
+    /// PERMISSIONLESS: Anyone can execute; the voting period stands in for the config timelock
+    pub fn execute_governance_proposal(
+        ctx: Context<ExecuteGovernanceProposal>,
+        _proposal_id: u64,
+    ) -> Result<()> {
+        let proposal = &mut ctx.accounts.governance_proposal;
+        let config = &mut ctx.accounts.platform_config;
+
+        require!(!proposal.executed, GovernanceError::ProposalAlreadyExecuted);
+        require!(!proposal.cancelled, GovernanceError::ProposalCancelled);
+        let now = Clock::get()?.unix_timestamp;
+        require!(now >= proposal.voting_ends_at, GovernanceError::VotingOpen);
+        let execution_deadline = proposal
+            .voting_ends_at
+            .checked_add(GOVERNANCE_EXECUTION_WINDOW)
+            .ok_or(GovernanceError::MathOverflow)?;
+        require!(now < execution_deadline, GovernanceError::ProposalExpired);
+
+        let votes_cast = proposal
+            .votes_for
+            .checked_add(proposal.votes_against)
+            .ok_or(GovernanceError::MathOverflow)?;
+        let quorum = proposal
+            .total_weight_snapshot
+            .saturating_mul(proposal.quorum_bps as u128)
+            / BPS_DENOMINATOR as u128;
+        require!(votes_cast > 0 && votes_cast >= quorum, GovernanceError::QuorumNotMet);
+        require!(
+            proposal.votes_for > proposal.votes_against,
+            GovernanceError::ProposalRejected
+        );
+
+        match proposal.action {
+            GovernanceAction::UpdateDistributionBounds { distribution_bounds } => {
+                require!(
+                    distribution_bounds.is_valid(),
+                    PlatformError::InvalidDistributionBounds
+                );
+                config.distribution_bounds = distribution_bounds;
+
+                msg!("✅ Distribution bounds updated by governance");
+            }
+            GovernanceAction::UpdateDeveloperShare { developer_share_bps } => {
+                let platform_share_bps = (BPS_DENOMINATOR as u16)
+                    .checked_sub(developer_share_bps)
+                    .ok_or(PlatformError::InvalidSharePercentages)?;
+                config.developer_share_bps = developer_share_bps;
+                config.platform_share_bps = platform_share_bps;
+                // A queued delay change must not restore the old shares when it executes
+                if config.queued_config_eta != 0 {
+                    config.queued_developer_share_bps = developer_share_bps;
+                    config.queued_platform_share_bps = platform_share_bps;
+                }
+
+                msg!(
+                    "✅ Revenue shares updated by governance: {}% developer, {}% platform",
+                    developer_share_bps / 100,
+                    platform_share_bps / 100
+                );
+            }
+        }
+
+        proposal.executed = true;
+
+        msg!("✅ Governance proposal {} executed", proposal.proposal_id);
+
+        Ok(())
+    }
+
+    /// Withdraw a governance proposal that has not been executed (proposer only)
+    pub fn cancel_governance_proposal(
+        ctx: Context<CancelGovernanceProposal>,
+        _proposal_id: u64,
+    ) -> Result<()> {
+        let proposal = &mut ctx.accounts.governance_proposal;
+
+        require!(!proposal.executed, GovernanceError::ProposalAlreadyExecuted);
+        require!(!proposal.cancelled, GovernanceError::ProposalCancelled);
+
+        proposal.cancelled = true;
+
+        msg!(
+            "✅ Governance proposal {} cancelled by {}",
+            proposal.proposal_id,
+            ctx.accounts.proposer.key()
+        );
+
+        Ok(())
+    }
+
     // ==============================
     // GLOBAL POOL INITIALIZATION
     // ==============================
@@ -305,7 +1841,7 @@ pub mod multiversed_dapp {
 
         // Check if already initialized
         require!(
-            staking_pool.total_staked == 0,
+            !staking_pool.is_initialized,
             StakingError::AlreadyInitialized
         );
 
@@ -315,7 +1851,29 @@ pub mod multiversed_dapp {
         staking_pool.total_weight = 0;
         staking_pool.acc_reward_per_weight = 0;
         staking_pool.epoch_index = 0;
+        staking_pool.early_unstake_penalty_bps = 0;
+        staking_pool.undistributed_rewards = 0;
+        staking_pool.retired = false;
+        staking_pool.reward_rate = 0;
+        staking_pool.reward_period_end = 0;
+        staking_pool.last_update_time = Clock::get()?.unix_timestamp;
+        staking_pool.reward_stream_duration = 0;
+        staking_pool.emission_rate = 0;
+        staking_pool.emission_start = 0;
+        staking_pool.emission_end = 0;
+        staking_pool.emission_remaining = 0;
+        staking_pool.liquid = false;
+        staking_pool.receipt_mint = Pubkey::default();
+        staking_pool.liquid_reward_debt = 0;
+        staking_pool.liquid_pending_rewards = 0;
+        staking_pool.total_unbonding = 0;
+        staking_pool.unbonding_cooldown = 0;
+        staking_pool.epoch_duration = 0;
+        staking_pool.epoch_started_at = Clock::get()?.unix_timestamp;
+        staking_pool.reward_pool = Pubkey::default();
+        staking_pool.secondary_rewards = Vec::new();
         staking_pool.token_type = token_type;
+        staking_pool.is_initialized = true;
         staking_pool.bump = ctx.bumps.staking_pool;
 
         match token_type {
@@ -397,6 +1955,18 @@ pub mod multiversed_dapp {
             }
         }
 
+        // Only the super admin's pools are listed, so other admins cannot fill the registry
+        let registry = &mut ctx.accounts.pool_registry;
+        registry.bump = ctx.bumps.pool_registry;
+        if staking_pool.admin == ctx.accounts.platform_config.super_admin {
+            registry.register(RegisteredPool {
+                staking_pool: staking_pool.key(),
+                token_type,
+                mint: pool_seed_mint(token_type, &staking_pool.mint),
+                reward_pool: Pubkey::default(),
+            })?;
+        }
+
         msg!(
             "✅ Staking pool initialized by admin: {}, token_type: {:?}",
             staking_pool.admin,
@@ -500,875 +2070,1234 @@ pub mod multiversed_dapp {
         Ok(())
     }
 
-    // ==============================
-    // STAKING FUNCTIONS
-    // ==============================
+    /// Set the reward pool a staking pool pays out from (pool admin only)
+    /// The reward pool may hold a different asset than the staking pool
+    /// Once linked, it can only be changed while nothing is staked or owed from it
+    pub fn link_reward_pool(ctx: Context<LinkRewardPool>) -> Result<()> {
+        let staking_pool = &mut ctx.accounts.staking_pool;
+        if staking_pool.reward_pool != Pubkey::default() {
+            staking_pool.update_pool(Clock::get()?.unix_timestamp)?;
+            require!(
+                staking_pool.total_staked == 0
+                    && staking_pool.total_unbonding == 0
+                    && staking_pool.liquid_pending_rewards == 0
+                    && staking_pool.undistributed_rewards == 0
+                    && staking_pool.emission_remaining == 0,
+                StakingError::RewardPoolInUse
+            );
+        }
 
-    /// Stake tokens with a specified lock duration
-    /// PERMISSIONLESS: Any user can stake
-    pub fn stake(ctx: Context<Stake>, amount: u64, lock_duration: i64) -> Result<()> {
-        validate_lock_duration(lock_duration)?;
+        let reward_pool_key = ctx.accounts.reward_pool.key();
+        require!(
+            !staking_pool
+                .secondary_rewards
+                .iter()
+                .any(|reward| reward.reward_pool == reward_pool_key),
+            StakingError::SecondaryRewardExists
+        );
+        staking_pool.reward_pool = reward_pool_key;
 
-        let staking_pool = &mut ctx.accounts.staking_pool;
-        let user_staking_account = &mut ctx.accounts.user_staking_account;
+        let staking_pool_key = staking_pool.key();
+        if let Some(entry) = ctx.accounts.pool_registry.entry_mut(&staking_pool_key) {
+            entry.reward_pool = reward_pool_key;
+        }
 
-        let multiplier_bps = lock_multiplier_bps(lock_duration);
-        let new_weight = (amount as u128)
-            .saturating_mul(multiplier_bps as u128)
-            .checked_div(BPS_DENOMINATOR as u128)
-            .ok_or(StakingError::MathOverflow)?;
+        msg!("✅ Reward pool linked");
+        msg!("   Staking pool: {}", staking_pool_key);
+        msg!("   Reward pool: {}", reward_pool_key);
+        msg!("   Reward mint: {}", ctx.accounts.reward_pool.mint);
 
-        if user_staking_account.staked_amount > 0 {
-            let accumulated_per_user: u128 = user_staking_account
-                .weight
-                .saturating_mul(staking_pool.acc_reward_per_weight)
-                .checked_div(ACC_PRECISION)
-                .unwrap_or(0);
-            let pending_now: u128 =
-                accumulated_per_user.saturating_sub(user_staking_account.reward_debt);
-            if pending_now > 0 {
-                let add: u64 = pending_now.min(u128::from(u64::MAX)) as u64;
-                user_staking_account.pending_rewards =
-                    user_staking_account.pending_rewards.saturating_add(add);
-            }
-        }
+        Ok(())
+    }
 
-        user_staking_account.owner = ctx.accounts.user.key();
-        user_staking_account.staked_amount = user_staking_account
-            .staked_amount
-            .checked_add(amount)
-            .ok_or(StakingError::MathOverflow)?;
-        user_staking_account.stake_timestamp = Clock::get()?.unix_timestamp;
-        user_staking_account.lock_duration = lock_duration;
-        user_staking_account.weight = user_staking_account
-            .weight
-            .checked_add(new_weight)
-            .ok_or(StakingError::MathOverflow)?;
-        user_staking_account.reward_debt = user_staking_account
-            .weight
-            .saturating_mul(staking_pool.acc_reward_per_weight)
-            .checked_div(ACC_PRECISION)
-            .unwrap_or(0);
+    /// Add a reward accumulator paying stakers from another reward pool (pool admin only)
+    /// Revenue credited to that reward pool is split by weight like the primary rewards
+    pub fn add_secondary_reward_pool(ctx: Context<AddSecondaryRewardPool>) -> Result<()> {
+        let staking_pool = &mut ctx.accounts.staking_pool;
+        let reward_pool = &ctx.accounts.reward_pool;
+        let reward_pool_key = reward_pool.key();
 
-        staking_pool.total_staked = staking_pool
-            .total_staked
-            .checked_add(amount)
-            .ok_or(StakingError::MathOverflow)?;
-        staking_pool.total_weight = staking_pool
-            .total_weight
-            .checked_add(new_weight)
-            .ok_or(StakingError::MathOverflow)?;
+        require!(
+            !staking_pool.pays_from(&reward_pool_key),
+            StakingError::SecondaryRewardExists
+        );
+        require!(
+            staking_pool.secondary_rewards.len() < MAX_SECONDARY_REWARDS,
+            StakingError::TooManySecondaryRewards
+        );
 
-        match staking_pool.token_type {
-            TokenType::SOL => {
-                // ✅ Verify the pool_escrow_account is the correct SOL vault PDA
-                let staking_pool_key = staking_pool.key();
-                let sol_vault_seeds = &[SEED_SOL_VAULT, staking_pool_key.as_ref()];
-                let (sol_vault_pda, _bump) =
-                    Pubkey::find_program_address(sol_vault_seeds, ctx.program_id);
+        staking_pool.secondary_rewards.push(SecondaryReward {
+            reward_pool: reward_pool_key,
+            token_type: reward_pool.token_type,
+            mint: reward_pool.mint,
+            acc_reward_per_weight: 0,
+            undistributed_rewards: 0,
+        });
 
-                require!(
-                    ctx.accounts.pool_escrow_account.key() == sol_vault_pda,
-                    StakingError::InvalidEscrowAccount
-                );
+        msg!("✅ Secondary reward pool added");
+        msg!("   Staking pool: {}", staking_pool.key());
+        msg!("   Reward pool: {}", reward_pool_key);
+        msg!("   Token type: {:?}", reward_pool.token_type);
+
+        Ok(())
+    }
+
+    /// Deposit SOL or SPL into a staking pool's reward pool
+    /// PERMISSIONLESS without a schedule: the deposit is credited like tournament revenue
+    /// With a schedule (pool admin only) it is emitted over [start, end) at `rate` per second
+    pub fn fund_reward_pool(
+        ctx: Context<FundRewardPool>,
+        amount: u64,
+        schedule: Option<EmissionSchedule>,
+    ) -> Result<()> {
+        require!(amount > 0, RewardError::InvalidAmount);
+
+        let now = Clock::get()?.unix_timestamp;
 
-                // Transfer SOL to vault using System Program
+        match ctx.accounts.reward_pool.token_type {
+            TokenType::SOL => {
                 system_program::transfer(
                     CpiContext::new(
                         ctx.accounts.system_program.to_account_info(),
                         system_program::Transfer {
-                            from: ctx.accounts.user.to_account_info(),
-                            to: ctx.accounts.pool_escrow_account.to_account_info(),
+                            from: ctx.accounts.funder.to_account_info(),
+                            to: ctx.accounts.reward_pool.to_account_info(),
                         },
                     ),
                     amount,
                 )?;
 
-                msg!("✅ {} lamports SOL staked to vault", amount);
-                msg!("   From: {}", ctx.accounts.user.key());
-                msg!("   To SOL Vault: {}", sol_vault_pda);
+                msg!("✅ {} lamports SOL deposited to reward pool", amount);
             }
             TokenType::SPL => {
-                let mint_data = ctx.accounts.mint.try_borrow_data()?;
-                let mint = Mint::try_deserialize(&mut &mint_data[..])?;
-                let mint_decimals = mint.decimals;
+                let reward_pool_key = ctx.accounts.reward_pool.key();
+                let (reward_escrow_pda, _bump) = Pubkey::find_program_address(
+                    &[SEED_REWARD_ESCROW, reward_pool_key.as_ref()],
+                    ctx.program_id,
+                );
+                require!(
+                    ctx.accounts.reward_escrow_account.key() == reward_escrow_pda,
+                    RewardError::InvalidEscrowAccount
+                );
+
+                let mint_decimals = {
+                    let mint_data = ctx.accounts.mint.try_borrow_data()?;
+                    let mint = Mint::try_deserialize(&mut &mint_data[..])?;
+                    mint.decimals
+                };
 
                 token_2022::transfer_checked(
                     CpiContext::new(
                         ctx.accounts.token_program.to_account_info(),
                         TransferChecked {
-                            from: ctx.accounts.user_token_account.to_account_info(),
-                            to: ctx.accounts.pool_escrow_account.to_account_info(),
+                            from: ctx.accounts.funder_token_account.to_account_info(),
+                            to: ctx.accounts.reward_escrow_account.to_account_info(),
                             mint: ctx.accounts.mint.to_account_info(),
-                            authority: ctx.accounts.user.to_account_info(),
+                            authority: ctx.accounts.funder.to_account_info(),
                         },
                     ),
                     amount,
                     mint_decimals,
                 )?;
 
-                msg!("✅ {} SPL tokens staked", amount);
+                msg!("✅ {} SPL tokens deposited to reward pool", amount);
+            }
+        }
+
+        ctx.accounts.reward_pool.total_funds = ctx
+            .accounts
+            .reward_pool
+            .total_funds
+            .checked_add(amount)
+            .ok_or(RewardError::MathOverflow)?;
+
+        let staking_pool = &mut ctx.accounts.staking_pool;
+        match schedule {
+            None => {
+                staking_pool.credit_rewards(amount, now)?;
+            }
+            Some(schedule) => {
+                require!(
+                    ctx.accounts.funder.key() == staking_pool.admin,
+                    RewardError::Unauthorized
+                );
+                require!(
+                    schedule.rate > 0 && schedule.start >= now && schedule.end > schedule.start,
+                    RewardError::InvalidEmissionSchedule
+                );
+                let scheduled_total = schedule
+                    .rate
+                    .checked_mul((schedule.end - schedule.start) as u64)
+                    .ok_or(RewardError::MathOverflow)?;
+                require!(
+                    scheduled_total == amount,
+                    RewardError::InvalidEmissionSchedule
+                );
+
+                staking_pool.update_pool(now)?;
+                require!(
+                    staking_pool.emission_remaining == 0,
+                    RewardError::EmissionScheduleActive
+                );
+
+                staking_pool.emission_rate = schedule.rate;
+                staking_pool.emission_start = schedule.start;
+                staking_pool.emission_end = schedule.end;
+                staking_pool.emission_remaining = amount;
+
+                msg!(
+                    "✅ Emission schedule set: {} per second from {} to {}",
+                    schedule.rate,
+                    schedule.start,
+                    schedule.end
+                );
             }
         }
 
+        Ok(())
+    }
+
+    // ==============================
+    // LOCK TIER REGISTRY
+    // ==============================
+
+    /// Create the lock tier registry for a staking pool (pool admin only)
+    /// Seeded with the default 1/3/6/12 month tiers
+    pub fn initialize_lock_tiers(ctx: Context<InitializeLockTiers>) -> Result<()> {
+        let lock_tiers = &mut ctx.accounts.lock_tiers;
+
+        lock_tiers.staking_pool = ctx.accounts.staking_pool.key();
+        lock_tiers.tiers = vec![
+            LockTier {
+                lock_duration: ONE_MONTH,
+                multiplier_bps: MULTIPLIER_1M_BPS,
+                enabled: true,
+            },
+            LockTier {
+                lock_duration: THREE_MONTHS,
+                multiplier_bps: MULTIPLIER_3M_BPS,
+                enabled: true,
+            },
+            LockTier {
+                lock_duration: SIX_MONTHS,
+                multiplier_bps: MULTIPLIER_6M_BPS,
+                enabled: true,
+            },
+            LockTier {
+                lock_duration: TWELVE_MONTHS,
+                multiplier_bps: MULTIPLIER_12M_BPS,
+                enabled: true,
+            },
+        ];
+        lock_tiers.bump = ctx.bumps.lock_tiers;
+
         msg!(
-            "✅ {} tokens staked by user: {} for {} seconds (weight: {}, multiplier: {}x)",
-            amount,
-            ctx.accounts.user.key(),
-            lock_duration,
-            new_weight,
-            multiplier_bps as f64 / BPS_DENOMINATOR as f64
+            "✅ Lock tiers initialized for staking pool {} ({} tiers)",
+            lock_tiers.staking_pool,
+            lock_tiers.tiers.len()
         );
 
         Ok(())
     }
 
-    pub fn unstake(ctx: Context<Unstake>) -> Result<()> {
-        let user_staking_account = &mut ctx.accounts.user_staking_account;
-        let staking_pool = &mut ctx.accounts.staking_pool;
+    /// Append a new lock tier (pool admin only)
+    pub fn add_lock_tier(
+        ctx: Context<ManageLockTiers>,
+        lock_duration: i64,
+        multiplier_bps: u64,
+    ) -> Result<()> {
+        validate_lock_tier(lock_duration, multiplier_bps)?;
 
+        let lock_tiers = &mut ctx.accounts.lock_tiers;
         require!(
-            user_staking_account.staked_amount > 0,
-            StakingError::InsufficientStakedBalance
+            lock_tiers.tiers.len() < MAX_LOCK_TIERS,
+            StakingError::TooManyLockTiers
         );
 
-        let amount_in_base_units = user_staking_account.staked_amount;
-
-        let accumulated_per_user: u128 = user_staking_account
-            .weight
-            .saturating_mul(staking_pool.acc_reward_per_weight)
-            .checked_div(ACC_PRECISION)
-            .unwrap_or(0);
-        let pending_now: u128 =
-            accumulated_per_user.saturating_sub(user_staking_account.reward_debt);
-        if pending_now > 0 {
-            let add: u64 = pending_now.min(u128::from(u64::MAX)) as u64;
-            user_staking_account.pending_rewards =
-                user_staking_account.pending_rewards.saturating_add(add);
-        }
-
-        match staking_pool.token_type {
-            TokenType::SOL => {
-                // ✅ Verify the pool_escrow_account is the correct SOL vault PDA
-                let staking_pool_key = staking_pool.key();
-                let sol_vault_seeds = &[SEED_SOL_VAULT, staking_pool_key.as_ref()];
-                let (sol_vault_pda, sol_vault_bump) =
-                    Pubkey::find_program_address(sol_vault_seeds, ctx.program_id);
+        lock_tiers.tiers.push(LockTier {
+            lock_duration,
+            multiplier_bps,
+            enabled: true,
+        });
 
-                require!(
-                    ctx.accounts.pool_escrow_account.key() == sol_vault_pda,
-                    StakingError::InvalidEscrowAccount
-                );
+        msg!(
+            "✅ Lock tier {} added: {} seconds at {}x",
+            lock_tiers.tiers.len() - 1,
+            lock_duration,
+            multiplier_bps as f64 / BPS_DENOMINATOR as f64
+        );
 
-                // ✅ Use System Program transfer with PDA signer (not direct lamport manipulation)
-                let vault_signer_seeds =
-                    &[SEED_SOL_VAULT, staking_pool_key.as_ref(), &[sol_vault_bump]];
-                let signer_seeds: &[&[&[u8]]] = &[vault_signer_seeds];
+        Ok(())
+    }
 
-                system_program::transfer(
-                    CpiContext::new_with_signer(
-                        ctx.accounts.system_program.to_account_info(),
-                        system_program::Transfer {
-                            from: ctx.accounts.pool_escrow_account.to_account_info(),
-                            to: ctx.accounts.user.to_account_info(),
-                        },
-                        signer_seeds,
-                    ),
-                    amount_in_base_units,
-                )?;
+    /// Update or disable an existing lock tier (pool admin only)
+    /// Existing stakes keep the duration and multiplier they were opened with
+    pub fn update_lock_tier(
+        ctx: Context<ManageLockTiers>,
+        tier_index: u8,
+        lock_duration: i64,
+        multiplier_bps: u64,
+        enabled: bool,
+    ) -> Result<()> {
+        validate_lock_tier(lock_duration, multiplier_bps)?;
 
-                msg!(
-                    "✅ {} lamports SOL unstaked from vault",
-                    amount_in_base_units
-                );
-                msg!("   From SOL Vault: {}", sol_vault_pda);
-                msg!("   To: {}", ctx.accounts.user.key());
-            }
-            TokenType::SPL => {
-                let mint_data = ctx.accounts.mint.try_borrow_data()?;
-                let mint = Mint::try_deserialize(&mut &mint_data[..])?;
-                let mint_decimals = mint.decimals;
+        let lock_tiers = &mut ctx.accounts.lock_tiers;
+        let tier = lock_tiers
+            .tiers
+            .get_mut(tier_index as usize)
+            .ok_or(StakingError::InvalidLockTier)?;
 
-                let staking_pool_admin = staking_pool.admin;
-                let token_type_seed = [staking_pool.token_type as u8];
-                let staking_pool_bump = staking_pool.bump;
-                let staking_pool_seeds = &[
-                    SEED_STAKING_POOL,
-                    staking_pool_admin.as_ref(),
-                    token_type_seed.as_ref(),
-                    &[staking_pool_bump],
-                ];
-                let signer_seeds: &[&[&[u8]]] = &[staking_pool_seeds];
+        tier.lock_duration = lock_duration;
+        tier.multiplier_bps = multiplier_bps;
+        tier.enabled = enabled;
 
-                token_2022::transfer_checked(
-                    CpiContext::new_with_signer(
-                        ctx.accounts.token_program.to_account_info(),
-                        TransferChecked {
-                            from: ctx.accounts.pool_escrow_account.to_account_info(),
-                            to: ctx.accounts.user_token_account.to_account_info(),
-                            mint: ctx.accounts.mint.to_account_info(),
-                            authority: staking_pool.to_account_info(),
-                        },
-                        signer_seeds,
-                    ),
-                    amount_in_base_units,
-                    mint_decimals,
-                )?;
+        msg!(
+            "✅ Lock tier {} updated: {} seconds at {}x (enabled: {})",
+            tier_index,
+            lock_duration,
+            multiplier_bps as f64 / BPS_DENOMINATOR as f64,
+            enabled
+        );
 
-                msg!("✅ {} SPL tokens unstaked", amount_in_base_units);
-            }
-        }
+        Ok(())
+    }
 
-        user_staking_account.staked_amount = 0;
-        staking_pool.total_staked = staking_pool
-            .total_staked
-            .checked_sub(amount_in_base_units)
-            .ok_or(StakingError::MathOverflow)?;
+    /// Set the early unstake penalty in basis points (pool admin only)
+    /// A penalty of 0 disables `early_unstake`
+    pub fn set_early_unstake_penalty(
+        ctx: Context<ConfigureStakingPool>,
+        early_unstake_penalty_bps: u16,
+    ) -> Result<()> {
+        require!(
+            early_unstake_penalty_bps as u64 <= BPS_DENOMINATOR,
+            StakingError::InvalidPenalty
+        );
 
-        staking_pool.total_weight = staking_pool
-            .total_weight
-            .saturating_sub(user_staking_account.weight);
-        user_staking_account.weight = 0;
-        user_staking_account.reward_debt = 0;
+        let staking_pool = &mut ctx.accounts.staking_pool;
+        staking_pool.early_unstake_penalty_bps = early_unstake_penalty_bps;
 
         msg!(
-            "✅ User {} unstaked all tokens. Pending rewards: {}",
-            ctx.accounts.user.key(),
-            user_staking_account.pending_rewards
+            "✅ Early unstake penalty set to {}% for staking pool {}",
+            early_unstake_penalty_bps as f64 / 100.0,
+            staking_pool.key()
         );
 
         Ok(())
     }
 
-    /// Accrue rewards for the user (updates pending_rewards)
-    /// PERMISSIONLESS: User accrues their own rewards
-    pub fn accrue_rewards(ctx: Context<AccrueRewards>) -> Result<()> {
-        let staking_pool = &ctx.accounts.staking_pool;
-        let user_staking_account = &mut ctx.accounts.user_staking_account;
-
-        // Calculate accumulated rewards
-        let accumulated: u128 = user_staking_account
-            .weight
-            .saturating_mul(staking_pool.acc_reward_per_weight)
-            .checked_div(ACC_PRECISION)
-            .unwrap_or(0);
-
-        let pending_now: u128 = accumulated.saturating_sub(user_staking_account.reward_debt);
+    /// Set the unbonding cooldown in seconds (pool admin only)
+    /// A non-zero cooldown routes all exits through `request_unstake`
+    pub fn set_unbonding_cooldown(
+        ctx: Context<ConfigureStakingPool>,
+        unbonding_cooldown: i64,
+    ) -> Result<()> {
+        require!(
+            unbonding_cooldown >= 0,
+            StakingError::InvalidUnbondingCooldown
+        );
 
-        if pending_now > 0 {
-            let add: u64 = pending_now.min(u128::from(u64::MAX)) as u64;
-            user_staking_account.pending_rewards =
-                user_staking_account.pending_rewards.saturating_add(add);
-            user_staking_account.reward_debt = accumulated;
+        let staking_pool = &mut ctx.accounts.staking_pool;
+        staking_pool.unbonding_cooldown = unbonding_cooldown;
 
-            msg!(
-                "✅ Rewards accrued for user {}: {} (pending total: {})",
-                ctx.accounts.user.key(),
-                add,
-                user_staking_account.pending_rewards
-            );
-        } else {
-            msg!(
-                "ℹ️ No new rewards to accrue for user {}",
-                ctx.accounts.user.key()
-            );
-        }
+        msg!(
+            "✅ Unbonding cooldown set to {} seconds for staking pool {}",
+            unbonding_cooldown,
+            staking_pool.key()
+        );
 
         Ok(())
     }
 
-    /// Claim accumulated rewards
-    /// PERMISSIONLESS: User claims their own rewards
-    pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
+    /// Set the minimum epoch length in seconds (pool admin only)
+    /// 0 disables `advance_epoch`
+    pub fn set_epoch_duration(ctx: Context<ConfigureStakingPool>, epoch_duration: i64) -> Result<()> {
+        require!(epoch_duration >= 0, StakingError::InvalidEpochDuration);
+
         let staking_pool = &mut ctx.accounts.staking_pool;
-        let reward_pool = &mut ctx.accounts.reward_pool;
-        let user_staking_account = &mut ctx.accounts.user_staking_account;
+        staking_pool.epoch_duration = epoch_duration;
 
-        // Compute claimable based on accumulator
-        let accumulated: u128 = user_staking_account
-            .weight
-            .saturating_mul(staking_pool.acc_reward_per_weight)
-            .checked_div(ACC_PRECISION)
-            .unwrap_or(0);
-        let claimable_u128: u128 = accumulated
-            .saturating_sub(user_staking_account.reward_debt)
-            .saturating_add(user_staking_account.pending_rewards as u128);
-        let claimable: u64 = claimable_u128.min(u128::from(u64::MAX)) as u64;
+        msg!(
+            "✅ Epoch duration set to {} seconds for staking pool {}",
+            epoch_duration,
+            staking_pool.key()
+        );
 
-        require!(claimable > 0, StakingError::InsufficientStakedBalance);
+        Ok(())
+    }
 
-        // Ensure RewardPool has sufficient funds
+    /// Close the current epoch and record its EpochSnapshot
+    /// PERMISSIONLESS: Anyone can crank once the epoch duration has passed
+    pub fn advance_epoch(ctx: Context<AdvanceEpoch>) -> Result<()> {
+        let staking_pool = &mut ctx.accounts.staking_pool;
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(staking_pool.epoch_duration > 0, StakingError::InvalidEpochDuration);
         require!(
-            reward_pool.total_funds >= claimable,
-            TournamentError::InsufficientFunds
+            now >= staking_pool
+                .epoch_started_at
+                .saturating_add(staking_pool.epoch_duration),
+            StakingError::EpochNotElapsed
         );
 
-        // Transfer rewards based on token type
-        match reward_pool.token_type {
-            TokenType::SOL => {
-                // Transfer SOL from reward pool to user
-                let reward_pool_info = reward_pool.to_account_info();
-                let user_info = ctx.accounts.user.to_account_info();
+        staking_pool.update_pool(now)?;
 
-                **reward_pool_info.try_borrow_mut_lamports()? = reward_pool_info
-                    .lamports()
-                    .checked_sub(claimable)
-                    .ok_or(StakingError::MathOverflow)?;
+        let snapshot = &mut ctx.accounts.epoch_snapshot;
+        snapshot.staking_pool = staking_pool.key();
+        snapshot.epoch = staking_pool.epoch_index;
+        snapshot.total_weight = staking_pool.total_weight;
+        snapshot.total_staked = staking_pool.total_staked;
+        snapshot.acc_reward_per_weight = staking_pool.acc_reward_per_weight;
+        snapshot.recorded_at = now;
+        snapshot.bump = ctx.bumps.epoch_snapshot;
 
-                **user_info.try_borrow_mut_lamports()? = user_info
-                    .lamports()
-                    .checked_add(claimable)
-                    .ok_or(StakingError::MathOverflow)?;
+        staking_pool.epoch_index = staking_pool
+            .epoch_index
+            .checked_add(1)
+            .ok_or(StakingError::MathOverflow)?;
+        staking_pool.epoch_started_at = now;
 
-                msg!("✅ User claimed {} lamports SOL rewards", claimable);
-            }
-            TokenType::SPL => {
-                // Transfer SPL tokens from reward escrow
-                let mint_data = ctx.accounts.mint.try_borrow_data()?;
-                let mint = Mint::try_deserialize(&mut &mint_data[..])?;
-                let decimals = mint.decimals;
-
-                let reward_admin = reward_pool.admin;
-                let token_type_seed = [reward_pool.token_type as u8];
-                let reward_bump = reward_pool.bump;
-                let reward_pool_seeds = &[
-                    SEED_REWARD_POOL,
-                    reward_admin.as_ref(),
-                    token_type_seed.as_ref(),
-                    &[reward_bump],
-                ];
-                let signer_seeds: &[&[&[u8]]] = &[reward_pool_seeds];
+        msg!(
+            "✅ Epoch {} closed for staking pool {} (total weight: {})",
+            snapshot.epoch,
+            snapshot.staking_pool,
+            snapshot.total_weight
+        );
 
-                token_2022::transfer_checked(
-                    CpiContext::new_with_signer(
-                        ctx.accounts.token_program.to_account_info(),
-                        TransferChecked {
-                            from: ctx.accounts.reward_escrow_account.to_account_info(),
-                            to: ctx.accounts.user_token_account.to_account_info(),
-                            mint: ctx.accounts.mint.to_account_info(),
-                            authority: reward_pool.to_account_info(),
-                        },
-                        signer_seeds,
-                    ),
-                    claimable,
-                    decimals,
-                )?;
+        Ok(())
+    }
 
-                msg!("✅ User claimed {} SPL token rewards", claimable);
-            }
-        }
+    /// Weight a position had in the snapshot of `epoch`
+    pub fn position_weight_at_epoch(
+        ctx: Context<PositionWeightAtEpoch>,
+        _position_id: u64,
+        epoch: u64,
+    ) -> Result<u128> {
+        require!(
+            epoch < ctx.accounts.staking_pool.epoch_index,
+            StakingError::CheckpointUnavailable
+        );
+        let weight = ctx
+            .accounts
+            .user_staking_account
+            .weight_at_epoch(epoch)
+            .ok_or(StakingError::CheckpointUnavailable)?;
+        Ok(weight)
+    }
 
-        // Update accounting
-        reward_pool.total_funds = reward_pool.total_funds.saturating_sub(claimable);
-        user_staking_account.pending_rewards = 0;
-        user_staking_account.reward_debt = user_staking_account
-            .weight
-            .saturating_mul(staking_pool.acc_reward_per_weight)
-            .checked_div(ACC_PRECISION)
-            .unwrap_or(0);
+    /// Retire a staking pool (pool admin only)
+    /// Existing positions can still unstake and claim; new stakes are rejected
+    pub fn retire_staking_pool(ctx: Context<ConfigureStakingPool>) -> Result<()> {
+        let staking_pool = &mut ctx.accounts.staking_pool;
+        require!(!staking_pool.retired, StakingError::PoolRetired);
+        staking_pool.retired = true;
+
+        msg!("✅ Staking pool {} retired", staking_pool.key());
+
+        Ok(())
+    }
+
+    /// Set how long each reward deposit is streamed over (pool admin only)
+    /// 0 credits deposits instantly; applies to deposits made after the change
+    pub fn set_reward_stream_duration(
+        ctx: Context<ConfigureStakingPool>,
+        reward_stream_duration: i64,
+    ) -> Result<()> {
+        require!(
+            reward_stream_duration >= 0,
+            StakingError::InvalidStreamDuration
+        );
+
+        let staking_pool = &mut ctx.accounts.staking_pool;
+        staking_pool.update_pool(Clock::get()?.unix_timestamp)?;
+        staking_pool.reward_stream_duration = reward_stream_duration;
 
         msg!(
-            "✅ User {} claimed {} rewards",
-            ctx.accounts.user.key(),
-            claimable
+            "✅ Reward stream duration set to {} seconds for staking pool {}",
+            reward_stream_duration,
+            staking_pool.key()
+        );
+
+        Ok(())
+    }
+
+    /// Reclaim rewards that were never credited to any staker (pool admin only)
+    /// Requires a retired pool with no remaining weight
+    pub fn reclaim_undistributed_rewards(
+        ctx: Context<ReclaimUndistributedRewards>,
+    ) -> Result<()> {
+        ctx.accounts
+            .staking_pool
+            .update_pool(Clock::get()?.unix_timestamp)?;
+
+        let amount = ctx.accounts.staking_pool.undistributed_rewards;
+        require!(amount > 0, StakingError::NoUndistributedRewards);
+
+        pay_rewards(
+            &mut ctx.accounts.reward_pool,
+            ctx.accounts.admin.to_account_info(),
+            ctx.accounts.admin_token_account.to_account_info(),
+            ctx.accounts.reward_escrow_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            amount,
+        )?;
+
+        ctx.accounts.staking_pool.undistributed_rewards = 0;
+
+        msg!(
+            "✅ Reclaimed {} undistributed rewards from staking pool {}",
+            amount,
+            ctx.accounts.staking_pool.key()
         );
 
         Ok(())
     }
+
     // ==============================
-    // TOURNAMENT FUNCTIONS
+    // STAKING FUNCTIONS
     // ==============================
 
-    pub fn create_tournament_pool(
-        ctx: Context<CreateTournamentPool>,
-        tournament_id: String,
-        entry_fee: u64,
-        max_participants: u16,
-        end_time: i64,
-        token_type: TokenType,
-    ) -> Result<()> {
-        let tournament_pool = &mut ctx.accounts.tournament_pool;
-        let creator = &ctx.accounts.creator;
+    /// Open a new stake position under one of the pool's lock tiers
+    /// `position_id` must be the next id from the user's stake index
+    /// PERMISSIONLESS: Any user can stake
+    pub fn stake(ctx: Context<Stake>, position_id: u64, amount: u64, tier_index: u8) -> Result<()> {
+        require!(
+            ctx.accounts.beneficiary.key() == ctx.accounts.user.key(),
+            StakingError::Unauthorized
+        );
+        process_stake(ctx, position_id, amount, tier_index)
+    }
 
-        // Validate tournament parameters
-        require!(entry_fee > 0, TournamentError::InvalidEntryFee);
+    /// Open a stake position owned by `beneficiary`, funded by the signer
+    /// Only the beneficiary can unstake or claim; the funder is recorded on the position
+    /// The beneficiary must have opted in with `set_delegated_stake_minimum`
+    pub fn stake_for(
+        ctx: Context<Stake>,
+        position_id: u64,
+        amount: u64,
+        tier_index: u8,
+    ) -> Result<()> {
+        let min_delegated_stake = ctx.accounts.stake_index.min_delegated_stake;
         require!(
-            max_participants > 0 && max_participants <= MAX_TOURNAMENT_PARTICIPANTS,
-            TournamentError::InvalidMaxParticipants
+            min_delegated_stake > 0,
+            StakingError::DelegatedStakeNotAccepted
         );
+        require!(
+            amount >= min_delegated_stake,
+            StakingError::DelegatedStakeTooSmall
+        );
+        process_stake(ctx, position_id, amount, tier_index)
+    }
+
+    /// Accept `stake_for` positions of at least `min_amount` from other wallets
+    /// Passing 0 stops accepting them; existing positions are unaffected
+    pub fn set_delegated_stake_minimum(
+        ctx: Context<SetDelegatedStakeMinimum>,
+        min_amount: u64,
+    ) -> Result<()> {
+        let stake_index = &mut ctx.accounts.stake_index;
+        stake_index.init_if_new(
+            ctx.accounts.user.key(),
+            ctx.accounts.staking_pool.key(),
+            ctx.bumps.stake_index,
+        );
+        stake_index.min_delegated_stake = min_amount;
+
+        msg!("✅ Delegated stake minimum set to {}", min_amount);
+
+        Ok(())
+    }
+
+    /// Move a position to a longer lock tier without unstaking
+    /// Rewards are settled at the old weight; the new lock runs from now
+    /// The new tier may not carry a lower multiplier than the position has
+    pub fn extend_lock(ctx: Context<ExtendLock>, position_id: u64, tier_index: u8) -> Result<()> {
+        let tier = ctx.accounts.lock_tiers.get_enabled(tier_index)?;
+
+        let staking_pool = &mut ctx.accounts.staking_pool;
+        let user_staking_account = &mut ctx.accounts.user_staking_account;
 
-        let current_time = Clock::get()?.unix_timestamp;
-        require!(end_time > current_time, TournamentError::InvalidEndTime);
         require!(
-            end_time < current_time + (MAX_TOURNAMENT_DURATION_DAYS * 24 * 60 * 60),
-            TournamentError::InvalidEndTime
+            tier.lock_duration > user_staking_account.lock_duration,
+            StakingError::LockNotExtended
+        );
+        require!(
+            tier.multiplier_bps >= user_staking_account.multiplier_bps,
+            StakingError::MultiplierReduced
         );
 
-        // Convert tournament_id to a fixed-size byte array
-        let mut tournament_id_bytes = [0u8; 32];
-        let id_bytes = tournament_id.as_bytes();
-        let len = id_bytes.len().min(32);
-        tournament_id_bytes[..len].copy_from_slice(&id_bytes[..len]);
+        let now = Clock::get()?.unix_timestamp;
+        staking_pool.update_pool(now)?;
 
-        // Initialize tournament pool fields
-        tournament_pool.admin = creator.key();
-        tournament_pool.tournament_id = tournament_id_bytes;
-        tournament_pool.entry_fee = entry_fee;
-        tournament_pool.total_funds = 0;
-        tournament_pool.participant_count = 0;
-        tournament_pool.max_participants = max_participants;
-        tournament_pool.end_time = end_time;
-        tournament_pool.is_active = true;
-        tournament_pool.token_type = token_type;
-        tournament_pool.bump = ctx.bumps.tournament_pool;
+        // Settle rewards earned at the old weight
+        let accumulated_per_user: u128 = user_staking_account
+            .weight
+            .saturating_mul(staking_pool.acc_reward_per_weight)
+            .checked_div(ACC_PRECISION)
+            .unwrap_or(0);
+        let pending_now: u128 =
+            accumulated_per_user.saturating_sub(user_staking_account.reward_debt);
+        if pending_now > 0 {
+            let add: u64 = pending_now.min(u128::from(u64::MAX)) as u64;
+            user_staking_account.pending_rewards =
+                user_staking_account.pending_rewards.saturating_add(add);
+        }
+        user_staking_account.settle_secondary_rewards(staking_pool);
 
-        match token_type {
-            TokenType::SOL => {
-                // For SOL tournaments - no escrow needed
-                tournament_pool.mint = tournament_pool.key();
+        let old_weight = user_staking_account.weight;
+        let new_weight = (user_staking_account.staked_amount as u128)
+            .saturating_mul(tier.multiplier_bps as u128)
+            .checked_div(BPS_DENOMINATOR as u128)
+            .ok_or(StakingError::MathOverflow)?;
 
-                msg!("✅ SOL tournament pool created (no escrow needed)");
-                msg!("   Pool stores SOL directly in its account");
-            }
-            TokenType::SPL => {
-                // For SPL tournaments - create and initialize escrow
-                tournament_pool.mint = ctx.accounts.mint.key();
+        staking_pool.total_weight = staking_pool
+            .total_weight
+            .checked_sub(old_weight)
+            .and_then(|w| w.checked_add(new_weight))
+            .ok_or(StakingError::MathOverflow)?;
 
-                require!(
-                    ctx.accounts.token_program.key() == anchor_spl::token_2022::ID,
-                    TournamentError::InvalidTokenProgram
-                );
+        user_staking_account.stake_timestamp = now;
+        user_staking_account.lock_duration = tier.lock_duration;
+        user_staking_account.lock_tier = tier_index;
+        user_staking_account.multiplier_bps = tier.multiplier_bps;
+        user_staking_account.weight = new_weight;
+        user_staking_account.record_weight_checkpoint(staking_pool.epoch_index);
+        user_staking_account.reward_debt = new_weight
+            .saturating_mul(staking_pool.acc_reward_per_weight)
+            .checked_div(ACC_PRECISION)
+            .unwrap_or(0);
+        user_staking_account.sync_secondary_debts(staking_pool);
 
-                let tournament_pool_key = tournament_pool.key();
-                let escrow_seeds = &[SEED_ESCROW, tournament_pool_key.as_ref()];
-                let (escrow_pda, escrow_bump) =
-                    Pubkey::find_program_address(escrow_seeds, ctx.program_id);
+        msg!(
+            "✅ Position {} extended to {} seconds (weight: {} -> {})",
+            position_id,
+            tier.lock_duration,
+            old_weight,
+            new_weight
+        );
 
-                require!(
-                    ctx.accounts.pool_escrow_account.key() == escrow_pda,
-                    TournamentError::InvalidEscrowAccount
-                );
+        Ok(())
+    }
 
-                let rent = Rent::get()?;
-                let space = 165;
-                let lamports = rent.minimum_balance(space);
+    /// Unstake part or all of a position once its lock has expired
+    /// The position is closed once its balance reaches zero and its rewards are paid
+    pub fn unstake(ctx: Context<Unstake>, _position_id: u64, amount: u64) -> Result<()> {
+        process_unstake(ctx, amount, false)
+    }
 
-                // ✅ Get mutable reference for CPI
-                let escrow_account_info = ctx.accounts.pool_escrow_account.to_account_info();
+    /// Unstake before the lock expires, paying the pool's early unstake penalty
+    /// SOL penalties go to the reward pool; SPL penalties are burned
+    /// Not available on pools with an unbonding cooldown: `request_unstake` needs an expired lock
+    pub fn early_unstake(ctx: Context<Unstake>, _position_id: u64, amount: u64) -> Result<()> {
+        process_unstake(ctx, amount, true)
+    }
 
-                invoke_signed(
-                    &system_instruction::create_account(
-                        ctx.accounts.creator.key,
-                        &escrow_pda,
-                        lamports,
-                        space as u64,
-                        &anchor_spl::token_2022::ID,
-                    ),
-                    &[
-                        ctx.accounts.creator.to_account_info(),
-                        escrow_account_info.clone(), // This is now mutable in the CPI context
-                        ctx.accounts.system_program.to_account_info(),
-                    ],
-                    &[&[SEED_ESCROW, tournament_pool_key.as_ref(), &[escrow_bump]]],
-                )?;
+    /// Move part or all of an unlocked position into an unbonding ticket
+    /// The amount stops earning rewards and can be withdrawn after the pool's cooldown
+    pub fn request_unstake(
+        ctx: Context<RequestUnstake>,
+        position_id: u64,
+        ticket_id: u64,
+        amount: u64,
+    ) -> Result<()> {
+        let staking_pool = &mut ctx.accounts.staking_pool;
+        let stake_index = &mut ctx.accounts.stake_index;
+        let user_staking_account = &mut ctx.accounts.user_staking_account;
 
-                let init_account_ix = spl_token_2022::instruction::initialize_account3(
-                    &anchor_spl::token_2022::ID,
-                    &escrow_pda,
-                    &ctx.accounts.mint.key(),
-                    &tournament_pool.key(),
-                )?;
+        let now = Clock::get()?.unix_timestamp;
+        staking_pool.update_pool(now)?;
 
-                invoke(
-                    &init_account_ix,
-                    &[escrow_account_info, ctx.accounts.mint.to_account_info()],
-                )?;
+        require!(
+            amount > 0 && amount <= user_staking_account.staked_amount,
+            StakingError::InsufficientStakedBalance
+        );
+        require!(
+            ticket_id == stake_index.next_ticket_id,
+            StakingError::InvalidTicketId
+        );
 
-                msg!("✅ SPL tournament pool and escrow initialized");
-                msg!("   Mint: {}", ctx.accounts.mint.key());
-                msg!("   Escrow: {}", escrow_pda);
-            }
+        let lock_end = user_staking_account
+            .stake_timestamp
+            .saturating_add(user_staking_account.lock_duration);
+        require!(now >= lock_end, StakingError::StakeLockActive);
+
+        // Settle rewards earned so far; they stay claimable on the position
+        let accumulated_per_user: u128 = user_staking_account
+            .weight
+            .saturating_mul(staking_pool.acc_reward_per_weight)
+            .checked_div(ACC_PRECISION)
+            .unwrap_or(0);
+        let pending_now: u128 =
+            accumulated_per_user.saturating_sub(user_staking_account.reward_debt);
+        if pending_now > 0 {
+            let add: u64 = pending_now.min(u128::from(u64::MAX)) as u64;
+            user_staking_account.pending_rewards =
+                user_staking_account.pending_rewards.saturating_add(add);
         }
+        user_staking_account.settle_secondary_rewards(staking_pool);
+
+        let weight_removed: u128 = if amount == user_staking_account.staked_amount {
+            user_staking_account.weight
+        } else {
+            user_staking_account
+                .weight
+                .saturating_mul(amount as u128)
+                .checked_div(user_staking_account.staked_amount as u128)
+                .ok_or(StakingError::MathOverflow)?
+        };
+
+        user_staking_account.staked_amount -= amount;
+        user_staking_account.weight = user_staking_account.weight.saturating_sub(weight_removed);
+        user_staking_account.record_weight_checkpoint(staking_pool.epoch_index);
+        user_staking_account.reward_debt = user_staking_account
+            .weight
+            .saturating_mul(staking_pool.acc_reward_per_weight)
+            .checked_div(ACC_PRECISION)
+            .unwrap_or(0);
+        user_staking_account.sync_secondary_debts(staking_pool);
+
+        staking_pool.total_staked = staking_pool
+            .total_staked
+            .checked_sub(amount)
+            .ok_or(StakingError::MathOverflow)?;
+        staking_pool.total_weight = staking_pool.total_weight.saturating_sub(weight_removed);
+        staking_pool.total_unbonding = staking_pool
+            .total_unbonding
+            .checked_add(amount)
+            .ok_or(StakingError::MathOverflow)?;
+
+        let unbonding_ticket = &mut ctx.accounts.unbonding_ticket;
+        unbonding_ticket.owner = ctx.accounts.user.key();
+        unbonding_ticket.staking_pool = staking_pool.key();
+        unbonding_ticket.ticket_id = ticket_id;
+        unbonding_ticket.amount = amount;
+        unbonding_ticket.request_time = now;
+        unbonding_ticket.unlock_time = now
+            .checked_add(staking_pool.unbonding_cooldown)
+            .ok_or(StakingError::MathOverflow)?;
+        unbonding_ticket.bump = ctx.bumps.unbonding_ticket;
+
+        stake_index.next_ticket_id = stake_index
+            .next_ticket_id
+            .checked_add(1)
+            .ok_or(StakingError::MathOverflow)?;
 
         msg!(
-        "✅ Tournament pool created by {}: ID={}, entry_fee={}, max_participants={}, token_type={:?}",
-        creator.key(),
-        String::from_utf8_lossy(&tournament_id_bytes),
-        entry_fee,
-        max_participants,
-        token_type
-    );
+            "✅ User {} requested unstake of {} from position {} (ticket {}, unlocks at {})",
+            ctx.accounts.user.key(),
+            amount,
+            position_id,
+            ticket_id,
+            unbonding_ticket.unlock_time
+        );
+
+        // Nothing left to track on a fully unbonded position without rewards
+        if user_staking_account.staked_amount == 0
+            && user_staking_account.pending_rewards == 0
+            && !user_staking_account.has_pending_secondary_rewards()
+        {
+            stake_index.remove(position_id);
+            user_staking_account.close(ctx.accounts.user.to_account_info())?;
+        }
 
         Ok(())
     }
-    /// Register for tournament
-    /// PERMISSIONLESS: Any user can register
-    pub fn register_for_tournament(
-        ctx: Context<RegisterForTournament>,
-        _tournament_id: String,
-    ) -> Result<()> {
-        let tournament_pool = &mut ctx.accounts.tournament_pool;
-        let registration_account = &mut ctx.accounts.registration_account;
 
-        // Verify tournament is active
+    /// Withdraw an unbonding ticket once its cooldown has passed
+    pub fn withdraw_unbonded(ctx: Context<WithdrawUnbonded>, ticket_id: u64) -> Result<()> {
+        let amount = ctx.accounts.unbonding_ticket.amount;
         require!(
-            tournament_pool.is_active,
-            TournamentError::TournamentNotActive
+            Clock::get()?.unix_timestamp >= ctx.accounts.unbonding_ticket.unlock_time,
+            StakingError::UnbondingNotComplete
         );
 
-        // Verify tournament hasn't ended
-        let current_time = Clock::get()?.unix_timestamp;
+        let (escrow_pda, escrow_bump) =
+            staking_escrow_address(&ctx.accounts.staking_pool, ctx.program_id);
         require!(
-            current_time < tournament_pool.end_time,
-            TournamentError::TournamentEnded
+            ctx.accounts.pool_escrow_account.key() == escrow_pda,
+            StakingError::InvalidEscrowAccount
         );
 
-        // Verify tournament isn't full
-        require!(
-            tournament_pool.participant_count < tournament_pool.max_participants,
-            TournamentError::TournamentFull
-        );
-
-        let entry_fee = tournament_pool.entry_fee;
-
-        // Transfer entry fee based on token type
-        match tournament_pool.token_type {
+        let staking_pool = &ctx.accounts.staking_pool;
+        let staking_pool_key = staking_pool.key();
+        match staking_pool.token_type {
             TokenType::SOL => {
-                // ✅ Use System Program transfer for SOL
+                let vault_signer_seeds = &[SEED_SOL_VAULT, staking_pool_key.as_ref(), &[escrow_bump]];
+                let signer_seeds: &[&[&[u8]]] = &[vault_signer_seeds];
+
                 system_program::transfer(
-                    CpiContext::new(
+                    CpiContext::new_with_signer(
                         ctx.accounts.system_program.to_account_info(),
                         system_program::Transfer {
-                            from: ctx.accounts.user.to_account_info(),
-                            to: tournament_pool.to_account_info(),
+                            from: ctx.accounts.pool_escrow_account.to_account_info(),
+                            to: ctx.accounts.user.to_account_info(),
                         },
+                        signer_seeds,
                     ),
-                    entry_fee,
+                    amount,
                 )?;
 
-                msg!("✅ {} lamports SOL transferred as entry fee", entry_fee);
+                msg!("✅ {} lamports SOL withdrawn from vault", amount);
             }
             TokenType::SPL => {
-                // ✅ Validate token program is correct
-                require!(
-                    ctx.accounts.token_program.key() == anchor_spl::token_2022::ID,
-                    TournamentError::InvalidTokenProgram
-                );
-
-                // ✅ Validate escrow account
-                let tournament_pool_key = tournament_pool.key();
-                let escrow_seeds = &[SEED_ESCROW, tournament_pool_key.as_ref()];
-                let (escrow_pda, _bump) =
-                    Pubkey::find_program_address(escrow_seeds, ctx.program_id);
-
-                require!(
-                    ctx.accounts.pool_escrow_account.key() == escrow_pda,
-                    TournamentError::InvalidEscrowAccount
-                );
+                let mint_decimals = {
+                    let mint_data = ctx.accounts.mint.try_borrow_data()?;
+                    let mint = Mint::try_deserialize(&mut &mint_data[..])?;
+                    mint.decimals
+                };
 
-                // Transfer SPL tokens from user to tournament escrow
-                let mint_data = ctx.accounts.mint.try_borrow_data()?;
-                let mint = Mint::try_deserialize(&mut &mint_data[..])?;
-                let mint_decimals = mint.decimals;
+                let staking_pool_admin = staking_pool.admin;
+                let staking_pool_seed_mint = staking_pool.seed_mint();
+                let token_type_seed = [staking_pool.token_type as u8];
+                let staking_pool_bump = staking_pool.bump;
+                let staking_pool_seeds = &[
+                    SEED_STAKING_POOL,
+                    staking_pool_admin.as_ref(),
+                    staking_pool_seed_mint.as_ref(),
+                    token_type_seed.as_ref(),
+                    &[staking_pool_bump],
+                ];
+                let signer_seeds: &[&[&[u8]]] = &[staking_pool_seeds];
 
                 token_2022::transfer_checked(
-                    CpiContext::new(
+                    CpiContext::new_with_signer(
                         ctx.accounts.token_program.to_account_info(),
                         TransferChecked {
-                            from: ctx.accounts.user_token_account.to_account_info(),
-                            to: ctx.accounts.pool_escrow_account.to_account_info(),
+                            from: ctx.accounts.pool_escrow_account.to_account_info(),
+                            to: ctx.accounts.user_token_account.to_account_info(),
                             mint: ctx.accounts.mint.to_account_info(),
-                            authority: ctx.accounts.user.to_account_info(),
+                            authority: staking_pool.to_account_info(),
                         },
+                        signer_seeds,
                     ),
-                    entry_fee,
+                    amount,
                     mint_decimals,
                 )?;
 
-                msg!("✅ {} SPL tokens transferred as entry fee", entry_fee);
+                msg!("✅ {} SPL tokens withdrawn", amount);
             }
         }
 
-        // Update tournament pool
-        tournament_pool.total_funds += entry_fee;
-        tournament_pool.participant_count += 1;
-
-        // Initialize registration record
-        registration_account.user = ctx.accounts.user.key();
-        registration_account.tournament_pool = tournament_pool.key();
-        registration_account.is_initialized = true;
-        registration_account.registration_time = current_time;
-        registration_account.bump = ctx.bumps.registration_account;
+        let staking_pool = &mut ctx.accounts.staking_pool;
+        staking_pool.total_unbonding = staking_pool
+            .total_unbonding
+            .checked_sub(amount)
+            .ok_or(StakingError::MathOverflow)?;
 
         msg!(
-            "✅ User {} registered for tournament {} with {} tokens",
+            "✅ User {} withdrew unbonding ticket {} ({})",
             ctx.accounts.user.key(),
-            String::from_utf8_lossy(&tournament_pool.tournament_id),
-            entry_fee
+            ticket_id,
+            amount
         );
 
         Ok(())
     }
-    /// Initialize prize pool for a tournament
-    /// Initialize prize pool for a tournament
-    /// Can be called by tournament creator or auto-initialized
-    pub fn initialize_prize_pool(
-        ctx: Context<InitializePrizePool>,
-        _tournament_id: String,
-    ) -> Result<()> {
-        let prize_pool = &mut ctx.accounts.prize_pool;
-        let creator = &ctx.accounts.creator;
-        let tournament_pool = &ctx.accounts.tournament_pool;
 
-        // Use tournament_id from tournament_pool (already validated in Context)
-        let tournament_id_bytes = tournament_pool.tournament_id;
+    /// Accrue rewards for the user (updates pending_rewards)
+    /// PERMISSIONLESS: User accrues their own rewards
+    pub fn accrue_rewards(ctx: Context<AccrueRewards>, _position_id: u64) -> Result<()> {
+        let staking_pool = &mut ctx.accounts.staking_pool;
+        let user_staking_account = &mut ctx.accounts.user_staking_account;
 
-        // Initialize prize pool basic fields
-        prize_pool.admin = creator.key();
-        prize_pool.tournament_pool = tournament_pool.key();
-        prize_pool.tournament_id = tournament_id_bytes;
-        prize_pool.total_funds = 0;
-        prize_pool.distributed = false;
-        prize_pool.token_type = tournament_pool.token_type;
-        prize_pool.bump = ctx.bumps.prize_pool;
+        staking_pool.update_pool(Clock::get()?.unix_timestamp)?;
 
-        // Handle token-type-specific initialization
-        match tournament_pool.token_type {
-            TokenType::SOL => {
-                // For SOL, no escrow needed - use prize_pool pubkey as mint
-                prize_pool.mint = prize_pool.key();
+        // Calculate accumulated rewards
+        let accumulated: u128 = user_staking_account
+            .weight
+            .saturating_mul(staking_pool.acc_reward_per_weight)
+            .checked_div(ACC_PRECISION)
+            .unwrap_or(0);
 
-                msg!("✅ SOL prize pool initialized (no escrow needed)");
-                msg!(
-                    "   Tournament: {}",
-                    String::from_utf8_lossy(&tournament_id_bytes)
-                );
-            }
-            TokenType::SPL => {
-                // For SPL, initialize escrow account
-                prize_pool.mint = ctx.accounts.mint.key();
+        let pending_now: u128 = accumulated.saturating_sub(user_staking_account.reward_debt);
 
-                // Validate token program
-                require!(
-                    ctx.accounts.token_program.key() == anchor_spl::token_2022::ID,
-                    TournamentError::InvalidTokenProgram
-                );
+        if pending_now > 0 {
+            let add: u64 = pending_now.min(u128::from(u64::MAX)) as u64;
+            user_staking_account.pending_rewards =
+                user_staking_account.pending_rewards.saturating_add(add);
+            user_staking_account.reward_debt = accumulated;
 
-                // Validate escrow PDA
-                let prize_pool_key = prize_pool.key();
-                let escrow_seeds = &[SEED_PRIZE_ESCROW, prize_pool_key.as_ref()];
-                let (escrow_pda, escrow_bump) =
-                    Pubkey::find_program_address(escrow_seeds, ctx.program_id);
+            msg!(
+                "✅ Rewards accrued for user {}: {} (pending total: {})",
+                ctx.accounts.user.key(),
+                add,
+                user_staking_account.pending_rewards
+            );
+        } else {
+            msg!(
+                "ℹ️ No new rewards to accrue for user {}",
+                ctx.accounts.user.key()
+            );
+        }
 
-                require!(
-                    ctx.accounts.prize_escrow_account.key() == escrow_pda,
-                    TournamentError::InvalidEscrowAccount
-                );
+        user_staking_account.settle_secondary_rewards(staking_pool);
 
-                // Create the escrow account
-                let rent = Rent::get()?;
-                let space = 165; // Token account size
-                let lamports = rent.minimum_balance(space);
+        Ok(())
+    }
 
-                invoke_signed(
-                    &system_instruction::create_account(
-                        ctx.accounts.creator.key,
-                        &escrow_pda,
-                        lamports,
-                        space as u64,
-                        &anchor_spl::token_2022::ID,
-                    ),
-                    &[
-                        ctx.accounts.creator.to_account_info(),
-                        ctx.accounts.prize_escrow_account.to_account_info(),
-                        ctx.accounts.system_program.to_account_info(),
-                    ],
-                    &[&[SEED_PRIZE_ESCROW, prize_pool_key.as_ref(), &[escrow_bump]]],
-                )?;
+    /// Claim accumulated rewards
+    /// PERMISSIONLESS: User claims their own rewards
+    /// Pays what the reward pool can cover; the remainder stays pending on the position
+    /// Secondary reward assets are paid from SECONDARY_CLAIM_ACCOUNTS remaining accounts each,
+    /// in the order of the pool's secondary rewards; omitted trailing assets stay pending
+    pub fn claim_rewards<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimRewards<'info>>,
+        _position_id: u64,
+    ) -> Result<()> {
+        let staking_pool = &mut ctx.accounts.staking_pool;
+        let reward_pool = &mut ctx.accounts.reward_pool;
+        let user_staking_account = &mut ctx.accounts.user_staking_account;
 
-                // Initialize as token account
-                let init_account_ix = spl_token_2022::instruction::initialize_account3(
-                    &anchor_spl::token_2022::ID,
-                    &escrow_pda,
-                    &ctx.accounts.mint.key(),
-                    &prize_pool.key(),
-                )?;
+        staking_pool.update_pool(Clock::get()?.unix_timestamp)?;
 
-                invoke(
-                    &init_account_ix,
-                    &[
-                        ctx.accounts.prize_escrow_account.to_account_info(),
-                        ctx.accounts.mint.to_account_info(),
-                    ],
+        // Compute claimable based on accumulator
+        let accumulated: u128 = user_staking_account
+            .weight
+            .saturating_mul(staking_pool.acc_reward_per_weight)
+            .checked_div(ACC_PRECISION)
+            .unwrap_or(0);
+        let claimable_u128: u128 = accumulated
+            .saturating_sub(user_staking_account.reward_debt)
+            .saturating_add(user_staking_account.pending_rewards as u128);
+        let claimable: u64 = claimable_u128.min(u128::from(u64::MAX)) as u64;
+
+        // Pay what the reward pool can cover; the rest stays pending
+        let payable = claimable.min(reward_pool.total_funds);
+        if payable > 0 {
+            pay_rewards(
+                reward_pool,
+                ctx.accounts.user.to_account_info(),
+                ctx.accounts.user_token_account.to_account_info(),
+                ctx.accounts.reward_escrow_account.to_account_info(),
+                ctx.accounts.mint.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+                payable,
+            )?;
+
+            msg!(
+                "✅ User {} claimed {} rewards ({} still pending)",
+                ctx.accounts.user.key(),
+                payable,
+                claimable - payable
+            );
+        }
+
+        // Update accounting
+        user_staking_account.pending_rewards = claimable - payable;
+        user_staking_account.reward_debt = accumulated;
+
+        // Secondary assets pay what their reward pool can cover; the rest stays pending
+        user_staking_account.settle_secondary_rewards(staking_pool);
+        let mut secondary_claimed = false;
+        for (index, accounts) in ctx
+            .remaining_accounts
+            .chunks(SECONDARY_CLAIM_ACCOUNTS)
+            .enumerate()
+        {
+            require!(
+                accounts.len() == SECONDARY_CLAIM_ACCOUNTS
+                    && index < staking_pool.secondary_rewards.len(),
+                StakingError::InvalidSecondaryAccounts
+            );
+            let secondary_reward = &staking_pool.secondary_rewards[index];
+            require!(
+                accounts[0].key() == secondary_reward.reward_pool,
+                StakingError::InvalidRewardPool
+            );
+            if secondary_reward.token_type == TokenType::SPL {
+                let (reward_escrow_pda, _bump) = Pubkey::find_program_address(
+                    &[SEED_REWARD_ESCROW, accounts[0].key.as_ref()],
+                    ctx.program_id,
+                );
+                require!(
+                    accounts[1].key() == reward_escrow_pda,
+                    RewardError::InvalidEscrowAccount
+                );
+                require!(
+                    accounts[3].key() == secondary_reward.mint,
+                    RewardError::InvalidMint
+                );
+            }
+
+            let mut secondary_pool: Account<'info, RewardPool> = Account::try_from(&accounts[0])?;
+            let position = &mut user_staking_account.secondary_rewards[index];
+            let payable = position.pending_rewards.min(secondary_pool.total_funds);
+            if payable > 0 {
+                pay_rewards(
+                    &mut secondary_pool,
+                    ctx.accounts.user.to_account_info(),
+                    accounts[2].clone(),
+                    accounts[1].clone(),
+                    accounts[3].clone(),
+                    ctx.accounts.token_program.to_account_info(),
+                    payable,
                 )?;
+                secondary_pool.exit(ctx.program_id)?;
+                position.pending_rewards -= payable;
+                secondary_claimed = true;
 
-                msg!("✅ SPL prize pool and escrow initialized");
                 msg!(
-                    "   Tournament: {}",
-                    String::from_utf8_lossy(&tournament_id_bytes)
+                    "✅ User {} claimed {} rewards from {}",
+                    ctx.accounts.user.key(),
+                    payable,
+                    secondary_pool.key()
                 );
-                msg!("   Mint: {}", ctx.accounts.mint.key());
-                msg!("   Escrow: {}", escrow_pda);
             }
         }
 
-        Ok(())
-    }
+        require!(
+            payable > 0 || secondary_claimed,
+            StakingError::NoRewardsToClaim
+        );
 
-    // ==============================
-    // REVENUE DISTRIBUTION (UPDATED WITH 90/10 SPLIT)
-    // ==============================
+        // A fully unstaked position only remains open as a residual reward claim
+        if user_staking_account.staked_amount == 0
+            && user_staking_account.pending_rewards == 0
+            && !user_staking_account.has_pending_secondary_rewards()
+        {
+            ctx.accounts
+                .stake_index
+                .remove(user_staking_account.position_id);
+            user_staking_account.close(ctx.accounts.user.to_account_info())?;
+        }
 
-    /// Distribute tournament revenue with 90/10 developer/platform split
-    /// Developer receives 90% of revenue portion directly to their wallet
-    /// Platform receives 10% of revenue portion directly to platform wallet
-    pub fn distribute_tournament_revenue(
-        ctx: Context<DistributeTournamentRevenue>,
-        tournament_id: String,
-        prize_percentage: u8,
-        revenue_percentage: u8,
-        staking_percentage: u8,
-        burn_percentage: u8,
-    ) -> Result<()> {
-        // Validate percentages sum to 100
-        let total = prize_percentage
-            .checked_add(revenue_percentage)
-            .and_then(|sum| sum.checked_add(staking_percentage))
-            .and_then(|sum| sum.checked_add(burn_percentage))
-            .ok_or(TournamentError::MathOverflow)?;
+        Ok(())
+    }
 
-        require!(total == 100, TournamentError::InvalidPercentages);
+    /// Restake a position's claimable rewards into its principal
+    /// Only for SPL pools whose reward mint is the staking mint; the lock is not reset
+    pub fn compound_rewards(ctx: Context<CompoundRewards>, position_id: u64) -> Result<()> {
+        let staking_pool = &mut ctx.accounts.staking_pool;
+        let user_staking_account = &mut ctx.accounts.user_staking_account;
 
-        let tournament_pool = &mut ctx.accounts.tournament_pool;
-        let platform_config = &ctx.accounts.platform_config;
+        let now = Clock::get()?.unix_timestamp;
+        staking_pool.update_pool(now)?;
 
-        // Verify tournament has ended
-        let current_time = Clock::get()?.unix_timestamp;
-        require!(
-            current_time >= tournament_pool.end_time,
-            TournamentError::TournamentNotActive
-        );
-
-        // Verify tournament is still active (not already distributed)
-        require!(
-            tournament_pool.is_active,
-            TournamentError::TournamentNotActive
-        );
+        let accumulated: u128 = user_staking_account
+            .weight
+            .saturating_mul(staking_pool.acc_reward_per_weight)
+            .checked_div(ACC_PRECISION)
+            .unwrap_or(0);
+        let claimable_u128: u128 = accumulated
+            .saturating_sub(user_staking_account.reward_debt)
+            .saturating_add(user_staking_account.pending_rewards as u128);
+        let claimable: u64 = claimable_u128.min(u128::from(u64::MAX)) as u64;
 
-        let total_funds = tournament_pool.total_funds;
-        require!(total_funds > 0, TournamentError::InsufficientFunds);
+        require!(claimable > 0, StakingError::InsufficientStakedBalance);
+        user_staking_account.settle_secondary_rewards(staking_pool);
+
+        // Reward escrow -> staking escrow
+        pay_rewards(
+            &mut ctx.accounts.reward_pool,
+            ctx.accounts.user.to_account_info(),
+            ctx.accounts.pool_escrow_account.to_account_info(),
+            ctx.accounts.reward_escrow_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            claimable,
+        )?;
+
+        let added_weight = (claimable as u128)
+            .saturating_mul(user_staking_account.multiplier_bps as u128)
+            .checked_div(BPS_DENOMINATOR as u128)
+            .ok_or(StakingError::MathOverflow)?;
 
-        // Calculate base distribution amounts
-        let prize_amount = (total_funds as u128)
-            .saturating_mul(prize_percentage as u128)
-            .checked_div(100)
-            .ok_or(TournamentError::MathOverflow)? as u64;
+        user_staking_account.staked_amount = user_staking_account
+            .staked_amount
+            .checked_add(claimable)
+            .ok_or(StakingError::MathOverflow)?;
+        user_staking_account.weight = user_staking_account
+            .weight
+            .checked_add(added_weight)
+            .ok_or(StakingError::MathOverflow)?;
+        user_staking_account.record_weight_checkpoint(staking_pool.epoch_index);
+        user_staking_account.pending_rewards = 0;
+        user_staking_account.reward_debt = user_staking_account
+            .weight
+            .saturating_mul(staking_pool.acc_reward_per_weight)
+            .checked_div(ACC_PRECISION)
+            .unwrap_or(0);
+        user_staking_account.sync_secondary_debts(staking_pool);
 
-        let revenue_amount = (total_funds as u128)
-            .saturating_mul(revenue_percentage as u128)
-            .checked_div(100)
-            .ok_or(TournamentError::MathOverflow)? as u64;
+        staking_pool.total_staked = staking_pool
+            .total_staked
+            .checked_add(claimable)
+            .ok_or(StakingError::MathOverflow)?;
+        staking_pool.total_weight = staking_pool
+            .total_weight
+            .checked_add(added_weight)
+            .ok_or(StakingError::MathOverflow)?;
 
-        let staking_amount = (total_funds as u128)
-            .saturating_mul(staking_percentage as u128)
-            .checked_div(100)
-            .ok_or(TournamentError::MathOverflow)? as u64;
+        msg!(
+            "✅ Compounded {} rewards into position {} (added weight: {})",
+            claimable,
+            position_id,
+            added_weight
+        );
 
-        let burn_amount = (total_funds as u128)
-            .saturating_mul(burn_percentage as u128)
-            .checked_div(100)
-            .ok_or(TournamentError::MathOverflow)? as u64;
+        Ok(())
+    }
 
-        // ✅ NEW: Calculate developer and platform shares from revenue
-        let developer_share = (revenue_amount as u128)
-            .saturating_mul(platform_config.developer_share_bps as u128)
-            .checked_div(BPS_DENOMINATOR as u128)
-            .ok_or(TournamentError::MathOverflow)? as u64;
+    /// Close a fully unstaked position and free its slot in the stake index
+    /// Any residual pending rewards the reward pools could not pay are forfeited,
+    /// including secondary reward assets
+    pub fn close_stake_position(ctx: Context<CloseStakePosition>, position_id: u64) -> Result<()> {
+        let user_staking_account = &ctx.accounts.user_staking_account;
+        let forfeited = user_staking_account.pending_rewards;
 
-        let platform_share = revenue_amount.saturating_sub(developer_share);
+        ctx.accounts.stake_index.remove(position_id);
 
-        // Prepare signer seeds for tournament pool
-        let creator_key = tournament_pool.admin;
-        let tournament_id_bytes = tournament_id.as_bytes();
-        let token_type_seed = [tournament_pool.token_type as u8];
-        let bump = tournament_pool.bump;
+        msg!(
+            "✅ Position {} closed for user {} (forfeited rewards: {})",
+            position_id,
+            ctx.accounts.user.key(),
+            forfeited
+        );
+        for (reward, position) in ctx
+            .accounts
+            .staking_pool
+            .secondary_rewards
+            .iter()
+            .zip(user_staking_account.secondary_rewards.iter())
+        {
+            if position.pending_rewards > 0 {
+                msg!(
+                    "   Forfeited {} secondary rewards from {}",
+                    position.pending_rewards,
+                    reward.reward_pool
+                );
+            }
+        }
 
-        let tournament_seeds = &[
-            SEED_TOURNAMENT_POOL,
-            creator_key.as_ref(),
-            tournament_id_bytes,
-            token_type_seed.as_ref(),
-            &[bump],
-        ];
-        let signer_seeds: &[&[&[u8]]] = &[tournament_seeds];
+        Ok(())
+    }
 
-        // Distribute based on token type
-        match tournament_pool.token_type {
-            TokenType::SOL => {
-                let tournament_pool_info = tournament_pool.to_account_info();
+    /// List the ids of a user's open positions in a staking pool
+    pub fn list_stake_positions(ctx: Context<ListStakePositions>) -> Result<Vec<u64>> {
+        Ok(ctx.accounts.stake_index.open_positions.clone())
+    }
 
-                // Prize pool transfer
-                if prize_amount > 0 {
-                    **tournament_pool_info.try_borrow_mut_lamports()? -= prize_amount;
-                    **ctx
-                        .accounts
-                        .prize_pool
-                        .to_account_info()
-                        .try_borrow_mut_lamports()? += prize_amount;
-                    ctx.accounts.prize_pool.total_funds += prize_amount;
-                }
+    // ==============================
+    // LIQUID STAKING
+    // ==============================
 
-                // ✅ NEW: Developer share - direct to developer wallet (90% of revenue)
-                if developer_share > 0 {
-                    **tournament_pool_info.try_borrow_mut_lamports()? -= developer_share;
-                    **ctx
-                        .accounts
-                        .developer_wallet
-                        .try_borrow_mut_lamports()? += developer_share;
-                }
+    /// Switch an empty staking pool to liquid mode and create its receipt mint (pool admin only)
+    /// Liquid pools have no lock tiers and need a reward pool paying in the staked asset
+    pub fn enable_liquid_staking(ctx: Context<EnableLiquidStaking>) -> Result<()> {
+        require!(
+            ctx.accounts.token_program.key() == anchor_spl::token_2022::ID,
+            StakingError::InvalidTokenProgram
+        );
 
-                // ✅ NEW: Platform share - direct to platform wallet (10% of revenue)
-                if platform_share > 0 {
-                    **tournament_pool_info.try_borrow_mut_lamports()? -= platform_share;
-                    **ctx
-                        .accounts
-                        .platform_wallet
-                        .try_borrow_mut_lamports()? += platform_share;
-                }
+        let staking_pool = &mut ctx.accounts.staking_pool;
+        let staking_pool_key = staking_pool.key();
 
-                // Staking rewards
-                if staking_amount > 0 {
-                    **tournament_pool_info.try_borrow_mut_lamports()? -= staking_amount;
-                    **ctx
-                        .accounts
-                        .reward_pool
-                        .to_account_info()
-                        .try_borrow_mut_lamports()? += staking_amount;
-                    ctx.accounts.reward_pool.total_funds += staking_amount;
+        // Receipt tokens mirror the decimals of the staked asset
+        let decimals = match staking_pool.token_type {
+            TokenType::SOL => 9,
+            TokenType::SPL => {
+                require!(
+                    ctx.accounts.mint.key() == staking_pool.mint,
+                    StakingError::LiquidNotSupported
+                );
+                let mint_data = ctx.accounts.mint.try_borrow_data()?;
+                let mint = Mint::try_deserialize(&mut &mint_data[..])?;
+                mint.decimals
+            }
+        };
+
+        let receipt_mint_bump = ctx.bumps.receipt_mint;
+        let rent = Rent::get()?;
+        let space = 82; // Mint account size for Token-2022 (no extensions)
+        let lamports = rent.minimum_balance(space);
+
+        invoke_signed(
+            &system_instruction::create_account(
+                ctx.accounts.admin.key,
+                &ctx.accounts.receipt_mint.key(),
+                lamports,
+                space as u64,
+                &anchor_spl::token_2022::ID,
+            ),
+            &[
+                ctx.accounts.admin.to_account_info(),
+                ctx.accounts.receipt_mint.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            &[&[SEED_RECEIPT_MINT, staking_pool_key.as_ref(), &[receipt_mint_bump]]],
+        )?;
+
+        let init_mint_ix = spl_token_2022::instruction::initialize_mint2(
+            &anchor_spl::token_2022::ID,
+            &ctx.accounts.receipt_mint.key(),
+            &staking_pool_key,
+            None,
+            decimals,
+        )?;
+
+        invoke(
+            &init_mint_ix,
+            &[ctx.accounts.receipt_mint.to_account_info()],
+        )?;
+
+        staking_pool.liquid = true;
+        staking_pool.receipt_mint = ctx.accounts.receipt_mint.key();
+        staking_pool.liquid_reward_debt = 0;
+        staking_pool.liquid_pending_rewards = 0;
+
+        msg!("✅ Liquid staking enabled for staking pool {}", staking_pool_key);
+        msg!("   Receipt mint: {}", staking_pool.receipt_mint);
 
-                    // Update staking pool accumulator
-                    let staking_pool = &mut ctx.accounts.staking_pool;
-                    if staking_pool.total_weight > 0 {
-                        let delta: u128 = (staking_amount as u128)
-                            .saturating_mul(ACC_PRECISION)
-                            .checked_div(staking_pool.total_weight)
-                            .unwrap_or(0);
-                        staking_pool.acc_reward_per_weight =
-                            staking_pool.acc_reward_per_weight.saturating_add(delta);
-                    }
-                }
+        Ok(())
+    }
 
-                // Note: SOL cannot be "burned" - burn_amount stays in tournament pool or could be sent to a dead address
-                // For now, we'll send it to the platform wallet as additional revenue
-                if burn_amount > 0 {
-                    **tournament_pool_info.try_borrow_mut_lamports()? -= burn_amount;
-                    **ctx
-                        .accounts
-                        .platform_wallet
-                        .try_borrow_mut_lamports()? += burn_amount;
-                }
+    /// Stake into a liquid pool and receive receipt tokens at the current exchange rate
+    /// PERMISSIONLESS: Any user can stake
+    pub fn stake_liquid(ctx: Context<StakeLiquid>, amount: u64) -> Result<()> {
+        let (escrow_pda, _escrow_bump) =
+            staking_escrow_address(&ctx.accounts.staking_pool, ctx.program_id);
+        require!(
+            ctx.accounts.pool_escrow_account.key() == escrow_pda,
+            StakingError::InvalidEscrowAccount
+        );
 
-                msg!("✅ SOL tournament revenue distributed");
+        let now = Clock::get()?.unix_timestamp;
+        ctx.accounts.staking_pool.update_pool(now)?;
+        harvest_liquid_rewards(
+            &mut ctx.accounts.staking_pool,
+            &mut ctx.accounts.reward_pool,
+            ctx.accounts.pool_escrow_account.to_account_info(),
+            ctx.accounts.reward_escrow_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+        )?;
+
+        // Shares are priced against the pool value after compounding
+        let supply = ctx.accounts.receipt_mint.supply;
+        let total_staked = ctx.accounts.staking_pool.total_staked;
+        let shares: u64 = if supply == 0 || total_staked == 0 {
+            amount
+        } else {
+            ((amount as u128)
+                .saturating_mul(supply as u128)
+                .checked_div(total_staked as u128)
+                .ok_or(StakingError::MathOverflow)?)
+            .min(u128::from(u64::MAX)) as u64
+        };
+        require!(shares > 0, StakingError::InvalidShareAmount);
+
+        match ctx.accounts.staking_pool.token_type {
+            TokenType::SOL => {
+                system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: ctx.accounts.user.to_account_info(),
+                            to: ctx.accounts.pool_escrow_account.to_account_info(),
+                        },
+                    ),
+                    amount,
+                )?;
             }
             TokenType::SPL => {
                 let mint_decimals = {
@@ -1377,634 +3306,2687 @@ pub mod multiversed_dapp {
                     mint.decimals
                 };
 
-                // Prize pool transfer
-                if prize_amount > 0 {
-                    let prize_pool = &mut ctx.accounts.prize_pool;
-                    token_2022::transfer_checked(
-                        CpiContext::new_with_signer(
-                            ctx.accounts.token_program.to_account_info(),
-                            TransferChecked {
-                                from: ctx.accounts.tournament_escrow_account.to_account_info(),
-                                to: ctx.accounts.prize_escrow_account.to_account_info(),
-                                mint: ctx.accounts.mint.to_account_info(),
-                                authority: tournament_pool.to_account_info(),
-                            },
-                            signer_seeds,
-                        ),
-                        prize_amount,
-                        mint_decimals,
-                    )?;
-                    prize_pool.total_funds += prize_amount;
-                }
+                token_2022::transfer_checked(
+                    CpiContext::new(
+                        ctx.accounts.token_program.to_account_info(),
+                        TransferChecked {
+                            from: ctx.accounts.user_token_account.to_account_info(),
+                            to: ctx.accounts.pool_escrow_account.to_account_info(),
+                            mint: ctx.accounts.mint.to_account_info(),
+                            authority: ctx.accounts.user.to_account_info(),
+                        },
+                    ),
+                    amount,
+                    mint_decimals,
+                )?;
+            }
+        }
 
-                // ✅ NEW: Developer share - direct to developer token account (90% of revenue)
-                if developer_share > 0 {
-                    token_2022::transfer_checked(
-                        CpiContext::new_with_signer(
-                            ctx.accounts.token_program.to_account_info(),
-                            TransferChecked {
-                                from: ctx.accounts.tournament_escrow_account.to_account_info(),
-                                to: ctx.accounts.developer_token_account.to_account_info(),
-                                mint: ctx.accounts.mint.to_account_info(),
-                                authority: tournament_pool.to_account_info(),
-                            },
-                            signer_seeds,
-                        ),
-                        developer_share,
-                        mint_decimals,
-                    )?;
-                }
+        let staking_pool = &mut ctx.accounts.staking_pool;
+        staking_pool.total_staked = staking_pool
+            .total_staked
+            .checked_add(amount)
+            .ok_or(StakingError::MathOverflow)?;
+        staking_pool.total_weight = staking_pool
+            .total_weight
+            .checked_add(amount as u128)
+            .ok_or(StakingError::MathOverflow)?;
+        staking_pool.liquid_reward_debt = staking_pool
+            .total_weight
+            .saturating_mul(staking_pool.acc_reward_per_weight)
+            .checked_div(ACC_PRECISION)
+            .unwrap_or(0);
+        staking_pool.fold_undistributed_rewards(now)?;
+
+        let staking_pool_admin = staking_pool.admin;
+        let staking_pool_seed_mint = staking_pool.seed_mint();
+        let token_type_seed = [staking_pool.token_type as u8];
+        let staking_pool_bump = staking_pool.bump;
+        let staking_pool_seeds = &[
+            SEED_STAKING_POOL,
+            staking_pool_admin.as_ref(),
+            staking_pool_seed_mint.as_ref(),
+            token_type_seed.as_ref(),
+            &[staking_pool_bump],
+        ];
+        let signer_seeds: &[&[&[u8]]] = &[staking_pool_seeds];
+
+        token_2022::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.receipt_mint.to_account_info(),
+                    to: ctx.accounts.user_receipt_account.to_account_info(),
+                    authority: ctx.accounts.staking_pool.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            shares,
+        )?;
 
-                // ✅ NEW: Platform share - direct to platform token account (10% of revenue)
-                if platform_share > 0 {
-                    token_2022::transfer_checked(
-                        CpiContext::new_with_signer(
-                            ctx.accounts.token_program.to_account_info(),
-                            TransferChecked {
-                                from: ctx.accounts.tournament_escrow_account.to_account_info(),
-                                to: ctx.accounts.platform_token_account.to_account_info(),
-                                mint: ctx.accounts.mint.to_account_info(),
-                                authority: tournament_pool.to_account_info(),
-                            },
-                            signer_seeds,
-                        ),
-                        platform_share,
-                        mint_decimals,
-                    )?;
-                }
+        msg!(
+            "✅ {} staked by user {} for {} receipt tokens",
+            amount,
+            ctx.accounts.user.key(),
+            shares
+        );
 
-                // Staking rewards
-                if staking_amount > 0 {
-                    token_2022::transfer_checked(
-                        CpiContext::new_with_signer(
-                            ctx.accounts.token_program.to_account_info(),
-                            TransferChecked {
-                                from: ctx.accounts.tournament_escrow_account.to_account_info(),
-                                to: ctx.accounts.reward_escrow_account.to_account_info(),
-                                mint: ctx.accounts.mint.to_account_info(),
-                                authority: tournament_pool.to_account_info(),
-                            },
-                            signer_seeds,
-                        ),
-                        staking_amount,
-                        mint_decimals,
-                    )?;
-                    ctx.accounts.reward_pool.total_funds += staking_amount;
+        Ok(())
+    }
 
-                    let staking_pool = &mut ctx.accounts.staking_pool;
-                    if staking_pool.total_weight > 0 {
-                        let delta: u128 = (staking_amount as u128)
-                            .saturating_mul(ACC_PRECISION)
-                            .checked_div(staking_pool.total_weight)
-                            .unwrap_or(0);
-                        staking_pool.acc_reward_per_weight =
-                            staking_pool.acc_reward_per_weight.saturating_add(delta);
-                    }
-                }
+    /// Burn receipt tokens for the underlying, including compounded rewards
+    /// Pools with an unbonding cooldown use `request_unstake_liquid` instead
+    pub fn unstake_liquid(ctx: Context<UnstakeLiquid>, shares: u64) -> Result<()> {
+        require!(
+            ctx.accounts.staking_pool.unbonding_cooldown == 0,
+            StakingError::UnbondingRequired
+        );
 
-                // Burn tokens
-                if burn_amount > 0 {
-                    token_2022::burn(
-                        CpiContext::new_with_signer(
-                            ctx.accounts.token_program.to_account_info(),
-                            Burn {
-                                mint: ctx.accounts.mint.to_account_info(),
-                                from: ctx.accounts.tournament_escrow_account.to_account_info(),
-                                authority: tournament_pool.to_account_info(),
-                            },
-                            signer_seeds,
-                        ),
-                        burn_amount,
-                    )?;
-                }
+        let (escrow_pda, escrow_bump) =
+            staking_escrow_address(&ctx.accounts.staking_pool, ctx.program_id);
+        require!(
+            ctx.accounts.pool_escrow_account.key() == escrow_pda,
+            StakingError::InvalidEscrowAccount
+        );
 
-                msg!("✅ SPL tournament revenue distributed");
+        let amount = redeem_liquid_shares(
+            &mut ctx.accounts.staking_pool,
+            &mut ctx.accounts.reward_pool,
+            &ctx.accounts.receipt_mint,
+            ctx.accounts.user_receipt_account.to_account_info(),
+            ctx.accounts.user.to_account_info(),
+            ctx.accounts.pool_escrow_account.to_account_info(),
+            ctx.accounts.reward_escrow_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            shares,
+        )?;
+
+        let staking_pool_key = ctx.accounts.staking_pool.key();
+        match ctx.accounts.staking_pool.token_type {
+            TokenType::SOL => {
+                let vault_signer_seeds = &[SEED_SOL_VAULT, staking_pool_key.as_ref(), &[escrow_bump]];
+                let signer_seeds: &[&[&[u8]]] = &[vault_signer_seeds];
+
+                system_program::transfer(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: ctx.accounts.pool_escrow_account.to_account_info(),
+                            to: ctx.accounts.user.to_account_info(),
+                        },
+                        signer_seeds,
+                    ),
+                    amount,
+                )?;
+            }
+            TokenType::SPL => {
+                let mint_decimals = {
+                    let mint_data = ctx.accounts.mint.try_borrow_data()?;
+                    let mint = Mint::try_deserialize(&mut &mint_data[..])?;
+                    mint.decimals
+                };
+
+                let staking_pool = &ctx.accounts.staking_pool;
+                let staking_pool_admin = staking_pool.admin;
+                let staking_pool_seed_mint = staking_pool.seed_mint();
+                let token_type_seed = [staking_pool.token_type as u8];
+                let staking_pool_bump = staking_pool.bump;
+                let staking_pool_seeds = &[
+                    SEED_STAKING_POOL,
+                    staking_pool_admin.as_ref(),
+                    staking_pool_seed_mint.as_ref(),
+                    token_type_seed.as_ref(),
+                    &[staking_pool_bump],
+                ];
+                let signer_seeds: &[&[&[u8]]] = &[staking_pool_seeds];
+
+                token_2022::transfer_checked(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_program.to_account_info(),
+                        TransferChecked {
+                            from: ctx.accounts.pool_escrow_account.to_account_info(),
+                            to: ctx.accounts.user_token_account.to_account_info(),
+                            mint: ctx.accounts.mint.to_account_info(),
+                            authority: staking_pool.to_account_info(),
+                        },
+                        signer_seeds,
+                    ),
+                    amount,
+                    mint_decimals,
+                )?;
             }
         }
 
-        // Update tournament pool state
-        tournament_pool.is_active = false;
-        tournament_pool.total_funds = 0;
+        msg!(
+            "✅ User {} burned {} receipt tokens for {}",
+            ctx.accounts.user.key(),
+            shares,
+            amount
+        );
+
+        Ok(())
+    }
+
+    /// Burn receipt tokens into an unbonding ticket on pools with a cooldown
+    /// The underlying is withdrawn with `withdraw_unbonded` once the cooldown has passed
+    pub fn request_unstake_liquid(
+        ctx: Context<RequestUnstakeLiquid>,
+        ticket_id: u64,
+        shares: u64,
+    ) -> Result<()> {
+        let (escrow_pda, _escrow_bump) =
+            staking_escrow_address(&ctx.accounts.staking_pool, ctx.program_id);
+        require!(
+            ctx.accounts.pool_escrow_account.key() == escrow_pda,
+            StakingError::InvalidEscrowAccount
+        );
+
+        let stake_index = &mut ctx.accounts.stake_index;
+        stake_index.init_if_new(
+            ctx.accounts.user.key(),
+            ctx.accounts.staking_pool.key(),
+            ctx.bumps.stake_index,
+        );
+        require!(
+            ticket_id == stake_index.next_ticket_id,
+            StakingError::InvalidTicketId
+        );
+
+        let amount = redeem_liquid_shares(
+            &mut ctx.accounts.staking_pool,
+            &mut ctx.accounts.reward_pool,
+            &ctx.accounts.receipt_mint,
+            ctx.accounts.user_receipt_account.to_account_info(),
+            ctx.accounts.user.to_account_info(),
+            ctx.accounts.pool_escrow_account.to_account_info(),
+            ctx.accounts.reward_escrow_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            shares,
+        )?;
+
+        let staking_pool = &mut ctx.accounts.staking_pool;
+        staking_pool.total_unbonding = staking_pool
+            .total_unbonding
+            .checked_add(amount)
+            .ok_or(StakingError::MathOverflow)?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let unbonding_ticket = &mut ctx.accounts.unbonding_ticket;
+        unbonding_ticket.owner = ctx.accounts.user.key();
+        unbonding_ticket.staking_pool = staking_pool.key();
+        unbonding_ticket.ticket_id = ticket_id;
+        unbonding_ticket.amount = amount;
+        unbonding_ticket.request_time = now;
+        unbonding_ticket.unlock_time = now
+            .checked_add(staking_pool.unbonding_cooldown)
+            .ok_or(StakingError::MathOverflow)?;
+        unbonding_ticket.bump = ctx.bumps.unbonding_ticket;
+
+        let stake_index = &mut ctx.accounts.stake_index;
+        stake_index.next_ticket_id = stake_index
+            .next_ticket_id
+            .checked_add(1)
+            .ok_or(StakingError::MathOverflow)?;
 
         msg!(
-            "✅ Tournament revenue distributed - Prize: {}, Developer (90%): {}, Platform (10%): {}, Staking: {}, Burn: {}",
-            prize_amount,
-            developer_share,
-            platform_share,
-            staking_amount,
-            burn_amount
+            "✅ User {} burned {} receipt tokens into ticket {} ({}, unlocks at {})",
+            ctx.accounts.user.key(),
+            shares,
+            ticket_id,
+            amount,
+            unbonding_ticket.unlock_time
         );
 
         Ok(())
     }
-    /// Distribute prizes to tournament winners
-    /// Only callable by tournament creator
-    pub fn distribute_tournament_prizes(
-        ctx: Context<DistributeTournamentPrizes>,
+    // ==============================
+    // TOURNAMENT FUNCTIONS
+    // ==============================
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_tournament_pool(
+        ctx: Context<CreateTournamentPool>,
         tournament_id: String,
+        entry_fee: u64,
+        max_participants: u16,
+        end_time: i64,
+        token_type: TokenType,
+        prize_percentage: u8,
+        revenue_percentage: u8,
+        staking_percentage: u8,
+        burn_percentage: u8,
     ) -> Result<()> {
-        // Fixed percentages for the top 3 positions
-        const PERCENTAGES: [u8; 3] = [50, 30, 20]; // 1st: 50%, 2nd: 30%, 3rd: 20%
+        let tournament_pool = &mut ctx.accounts.tournament_pool;
+        let creator = &ctx.accounts.creator;
+        let platform_config = &ctx.accounts.platform_config;
 
-        let prize_pool = &mut ctx.accounts.prize_pool;
+        // Validate tournament parameters
+        require!(entry_fee > 0, TournamentError::InvalidEntryFee);
+        validate_distribution_percentages(
+            prize_percentage,
+            revenue_percentage,
+            staking_percentage,
+            burn_percentage,
+        )?;
+        validate_distribution_bounds(
+            &platform_config.distribution_bounds,
+            prize_percentage,
+            revenue_percentage,
+            staking_percentage,
+            burn_percentage,
+        )?;
+        require!(
+            max_participants > 0 && max_participants <= MAX_TOURNAMENT_PARTICIPANTS,
+            TournamentError::InvalidMaxParticipants
+        );
 
-        // Convert tournament_id to fixed-size bytes for comparison
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(end_time > current_time, TournamentError::InvalidEndTime);
+        require!(
+            end_time < current_time + (MAX_TOURNAMENT_DURATION_DAYS * 24 * 60 * 60),
+            TournamentError::InvalidEndTime
+        );
+
+        // Convert tournament_id to a fixed-size byte array
         let mut tournament_id_bytes = [0u8; 32];
         let id_bytes = tournament_id.as_bytes();
         let len = id_bytes.len().min(32);
         tournament_id_bytes[..len].copy_from_slice(&id_bytes[..len]);
 
-        // Verify tournament ID matches
-        require!(
-            prize_pool.tournament_id == tournament_id_bytes,
-            TournamentError::Unauthorized
-        );
+        // Initialize tournament pool fields
+        tournament_pool.admin = creator.key();
+        tournament_pool.tournament_id = tournament_id_bytes;
+        tournament_pool.entry_fee = entry_fee;
+        tournament_pool.total_funds = 0;
+        tournament_pool.participant_count = 0;
+        tournament_pool.max_participants = max_participants;
+        tournament_pool.end_time = end_time;
+        tournament_pool.is_active = true;
+        tournament_pool.token_type = token_type;
+        tournament_pool.prize_percentage = prize_percentage;
+        tournament_pool.revenue_percentage = revenue_percentage;
+        tournament_pool.staking_percentage = staking_percentage;
+        tournament_pool.burn_percentage = burn_percentage;
+        tournament_pool.developer_share_bps = platform_config.developer_share_bps;
+        tournament_pool.bump = ctx.bumps.tournament_pool;
 
-        // Ensure prize pool hasn't been distributed yet
-        require!(!prize_pool.distributed, TournamentError::AlreadyDistributed);
+        match token_type {
+            TokenType::SOL => {
+                // For SOL tournaments - no escrow needed
+                tournament_pool.mint = tournament_pool.key();
 
-        // Ensure there are funds to distribute
-        require!(
-            prize_pool.total_funds > 0,
-            TournamentError::InsufficientFunds
-        );
+                msg!("✅ SOL tournament pool created (no escrow needed)");
+                msg!("   Pool stores SOL directly in its account");
+            }
+            TokenType::SPL => {
+                // For SPL tournaments - create and initialize escrow
+                tournament_pool.mint = ctx.accounts.mint.key();
+
+                require!(
+                    ctx.accounts.token_program.key() == anchor_spl::token_2022::ID,
+                    TournamentError::InvalidTokenProgram
+                );
+
+                let tournament_pool_key = tournament_pool.key();
+                let escrow_seeds = &[SEED_ESCROW, tournament_pool_key.as_ref()];
+                let (escrow_pda, escrow_bump) =
+                    Pubkey::find_program_address(escrow_seeds, ctx.program_id);
+
+                require!(
+                    ctx.accounts.pool_escrow_account.key() == escrow_pda,
+                    TournamentError::InvalidEscrowAccount
+                );
+
+                let rent = Rent::get()?;
+                let space = 165;
+                let lamports = rent.minimum_balance(space);
+
+                // ✅ Get mutable reference for CPI
+                let escrow_account_info = ctx.accounts.pool_escrow_account.to_account_info();
+
+                invoke_signed(
+                    &system_instruction::create_account(
+                        ctx.accounts.creator.key,
+                        &escrow_pda,
+                        lamports,
+                        space as u64,
+                        &anchor_spl::token_2022::ID,
+                    ),
+                    &[
+                        ctx.accounts.creator.to_account_info(),
+                        escrow_account_info.clone(), // This is now mutable in the CPI context
+                        ctx.accounts.system_program.to_account_info(),
+                    ],
+                    &[&[SEED_ESCROW, tournament_pool_key.as_ref(), &[escrow_bump]]],
+                )?;
+
+                let init_account_ix = spl_token_2022::instruction::initialize_account3(
+                    &anchor_spl::token_2022::ID,
+                    &escrow_pda,
+                    &ctx.accounts.mint.key(),
+                    &tournament_pool.key(),
+                )?;
+
+                invoke(
+                    &init_account_ix,
+                    &[escrow_account_info, ctx.accounts.mint.to_account_info()],
+                )?;
+
+                msg!("✅ SPL tournament pool and escrow initialized");
+                msg!("   Mint: {}", ctx.accounts.mint.key());
+                msg!("   Escrow: {}", escrow_pda);
+            }
+        }
+
+        msg!(
+        "✅ Tournament pool created by {}: ID={}, entry_fee={}, max_participants={}, token_type={:?}",
+        creator.key(),
+        String::from_utf8_lossy(&tournament_id_bytes),
+        entry_fee,
+        max_participants,
+        token_type
+    );
+        msg!(
+            "   Split: prize {}%, revenue {}%, staking {}%, burn {}% (developer {}%, platform {}%)",
+            prize_percentage,
+            revenue_percentage,
+            staking_percentage,
+            burn_percentage,
+            platform_config.developer_share_bps / 100,
+            platform_config.platform_share_bps / 100
+        );
+
+        Ok(())
+    }
+    /// Register for tournament
+    /// PERMISSIONLESS: Any user can register
+    pub fn register_for_tournament(
+        ctx: Context<RegisterForTournament>,
+        _tournament_id: String,
+    ) -> Result<()> {
+        let tournament_pool = &mut ctx.accounts.tournament_pool;
+        let registration_account = &mut ctx.accounts.registration_account;
+
+        // Verify tournament is active
+        require!(
+            tournament_pool.is_active,
+            TournamentError::TournamentNotActive
+        );
+
+        // Verify tournament hasn't ended
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time < tournament_pool.end_time,
+            TournamentError::TournamentEnded
+        );
+
+        // Verify tournament isn't full
+        require!(
+            tournament_pool.participant_count < tournament_pool.max_participants,
+            TournamentError::TournamentFull
+        );
+
+        let entry_fee = tournament_pool.entry_fee;
+
+        // Transfer entry fee based on token type
+        match tournament_pool.token_type {
+            TokenType::SOL => {
+                // ✅ Use System Program transfer for SOL
+                system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: ctx.accounts.user.to_account_info(),
+                            to: tournament_pool.to_account_info(),
+                        },
+                    ),
+                    entry_fee,
+                )?;
+
+                msg!("✅ {} lamports SOL transferred as entry fee", entry_fee);
+            }
+            TokenType::SPL => {
+                // ✅ Validate token program is correct
+                require!(
+                    ctx.accounts.token_program.key() == anchor_spl::token_2022::ID,
+                    TournamentError::InvalidTokenProgram
+                );
+
+                // ✅ Validate escrow account
+                let tournament_pool_key = tournament_pool.key();
+                let escrow_seeds = &[SEED_ESCROW, tournament_pool_key.as_ref()];
+                let (escrow_pda, _bump) =
+                    Pubkey::find_program_address(escrow_seeds, ctx.program_id);
+
+                require!(
+                    ctx.accounts.pool_escrow_account.key() == escrow_pda,
+                    TournamentError::InvalidEscrowAccount
+                );
+
+                // Transfer SPL tokens from user to tournament escrow
+                let mint_data = ctx.accounts.mint.try_borrow_data()?;
+                let mint = Mint::try_deserialize(&mut &mint_data[..])?;
+                let mint_decimals = mint.decimals;
+
+                token_2022::transfer_checked(
+                    CpiContext::new(
+                        ctx.accounts.token_program.to_account_info(),
+                        TransferChecked {
+                            from: ctx.accounts.user_token_account.to_account_info(),
+                            to: ctx.accounts.pool_escrow_account.to_account_info(),
+                            mint: ctx.accounts.mint.to_account_info(),
+                            authority: ctx.accounts.user.to_account_info(),
+                        },
+                    ),
+                    entry_fee,
+                    mint_decimals,
+                )?;
+
+                msg!("✅ {} SPL tokens transferred as entry fee", entry_fee);
+            }
+        }
+
+        // Update tournament pool
+        tournament_pool.total_funds += entry_fee;
+        tournament_pool.participant_count += 1;
+
+        // Initialize registration record
+        registration_account.user = ctx.accounts.user.key();
+        registration_account.tournament_pool = tournament_pool.key();
+        registration_account.is_initialized = true;
+        registration_account.registration_time = current_time;
+        registration_account.bump = ctx.bumps.registration_account;
+
+        msg!(
+            "✅ User {} registered for tournament {} with {} tokens",
+            ctx.accounts.user.key(),
+            String::from_utf8_lossy(&tournament_pool.tournament_id),
+            entry_fee
+        );
+
+        Ok(())
+    }
+    /// Initialize prize pool for a tournament
+    /// Initialize prize pool for a tournament
+    /// Can be called by tournament creator or auto-initialized
+    pub fn initialize_prize_pool(
+        ctx: Context<InitializePrizePool>,
+        _tournament_id: String,
+    ) -> Result<()> {
+        let prize_pool = &mut ctx.accounts.prize_pool;
+        let creator = &ctx.accounts.creator;
+        let tournament_pool = &ctx.accounts.tournament_pool;
+
+        // Use tournament_id from tournament_pool (already validated in Context)
+        let tournament_id_bytes = tournament_pool.tournament_id;
+
+        // Initialize prize pool basic fields
+        prize_pool.admin = creator.key();
+        prize_pool.tournament_pool = tournament_pool.key();
+        prize_pool.tournament_id = tournament_id_bytes;
+        prize_pool.total_funds = 0;
+        prize_pool.distributed = false;
+        prize_pool.token_type = tournament_pool.token_type;
+        prize_pool.bump = ctx.bumps.prize_pool;
+
+        // Handle token-type-specific initialization
+        match tournament_pool.token_type {
+            TokenType::SOL => {
+                // For SOL, no escrow needed - use prize_pool pubkey as mint
+                prize_pool.mint = prize_pool.key();
+
+                msg!("✅ SOL prize pool initialized (no escrow needed)");
+                msg!(
+                    "   Tournament: {}",
+                    String::from_utf8_lossy(&tournament_id_bytes)
+                );
+            }
+            TokenType::SPL => {
+                // For SPL, initialize escrow account
+                prize_pool.mint = ctx.accounts.mint.key();
+
+                // Validate token program
+                require!(
+                    ctx.accounts.token_program.key() == anchor_spl::token_2022::ID,
+                    TournamentError::InvalidTokenProgram
+                );
+
+                // Validate escrow PDA
+                let prize_pool_key = prize_pool.key();
+                let escrow_seeds = &[SEED_PRIZE_ESCROW, prize_pool_key.as_ref()];
+                let (escrow_pda, escrow_bump) =
+                    Pubkey::find_program_address(escrow_seeds, ctx.program_id);
+
+                require!(
+                    ctx.accounts.prize_escrow_account.key() == escrow_pda,
+                    TournamentError::InvalidEscrowAccount
+                );
+
+                // Create the escrow account
+                let rent = Rent::get()?;
+                let space = 165; // Token account size
+                let lamports = rent.minimum_balance(space);
+
+                invoke_signed(
+                    &system_instruction::create_account(
+                        ctx.accounts.creator.key,
+                        &escrow_pda,
+                        lamports,
+                        space as u64,
+                        &anchor_spl::token_2022::ID,
+                    ),
+                    &[
+                        ctx.accounts.creator.to_account_info(),
+                        ctx.accounts.prize_escrow_account.to_account_info(),
+                        ctx.accounts.system_program.to_account_info(),
+                    ],
+                    &[&[SEED_PRIZE_ESCROW, prize_pool_key.as_ref(), &[escrow_bump]]],
+                )?;
+
+                // Initialize as token account
+                let init_account_ix = spl_token_2022::instruction::initialize_account3(
+                    &anchor_spl::token_2022::ID,
+                    &escrow_pda,
+                    &ctx.accounts.mint.key(),
+                    &prize_pool.key(),
+                )?;
+
+                invoke(
+                    &init_account_ix,
+                    &[
+                        ctx.accounts.prize_escrow_account.to_account_info(),
+                        ctx.accounts.mint.to_account_info(),
+                    ],
+                )?;
+
+                msg!("✅ SPL prize pool and escrow initialized");
+                msg!(
+                    "   Tournament: {}",
+                    String::from_utf8_lossy(&tournament_id_bytes)
+                );
+                msg!("   Mint: {}", ctx.accounts.mint.key());
+                msg!("   Escrow: {}", escrow_pda);
+            }
+        }
+
+        Ok(())
+    }
+
+    // ==============================
+    // REVENUE DISTRIBUTION (UPDATED WITH 90/10 SPLIT)
+    // ==============================
+
+    /// Distribute tournament revenue using the split stored on the tournament
+    /// Developer receives their share of the revenue portion directly to their wallet
+    /// Platform receives the remainder of the revenue portion directly to platform wallet
+    pub fn distribute_tournament_revenue(
+        ctx: Context<DistributeTournamentRevenue>,
+        tournament_id: String,
+    ) -> Result<()> {
+        let tournament_pool = &mut ctx.accounts.tournament_pool;
+
+        // Split was fixed at creation so players can verify it before registering;
+        // it must still fall within the current bounds to be distributed
+        let prize_percentage = tournament_pool.prize_percentage;
+        let revenue_percentage = tournament_pool.revenue_percentage;
+        let staking_percentage = tournament_pool.staking_percentage;
+        let burn_percentage = tournament_pool.burn_percentage;
+        validate_distribution_bounds(
+            &ctx.accounts.platform_config.distribution_bounds,
+            prize_percentage,
+            revenue_percentage,
+            staking_percentage,
+            burn_percentage,
+        )?;
+
+        // Verify tournament has ended
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time >= tournament_pool.end_time,
+            TournamentError::TournamentNotActive
+        );
+
+        // Verify tournament is still active (not already distributed)
+        require!(
+            tournament_pool.is_active,
+            TournamentError::TournamentNotActive
+        );
+
+        let total_funds = tournament_pool.total_funds;
+        require!(total_funds > 0, TournamentError::InsufficientFunds);
+
+        // Calculate base distribution amounts
+        let prize_amount = (total_funds as u128)
+            .saturating_mul(prize_percentage as u128)
+            .checked_div(100)
+            .ok_or(TournamentError::MathOverflow)? as u64;
+
+        let revenue_amount = (total_funds as u128)
+            .saturating_mul(revenue_percentage as u128)
+            .checked_div(100)
+            .ok_or(TournamentError::MathOverflow)? as u64;
+
+        let staking_amount = (total_funds as u128)
+            .saturating_mul(staking_percentage as u128)
+            .checked_div(100)
+            .ok_or(TournamentError::MathOverflow)? as u64;
+
+        let burn_amount = (total_funds as u128)
+            .saturating_mul(burn_percentage as u128)
+            .checked_div(100)
+            .ok_or(TournamentError::MathOverflow)? as u64;
+
+        // Developer share uses the split in effect when the tournament was created;
+        // the platform takes the remainder
+        let developer_share = (revenue_amount as u128)
+            .saturating_mul(tournament_pool.developer_share_bps as u128)
+            .checked_div(BPS_DENOMINATOR as u128)
+            .ok_or(TournamentError::MathOverflow)? as u64;
+
+        let platform_share = revenue_amount.saturating_sub(developer_share);
+
+        // Prepare signer seeds for tournament pool
+        let creator_key = tournament_pool.admin;
+        let tournament_id_bytes = tournament_id.as_bytes();
+        let token_type_seed = [tournament_pool.token_type as u8];
+        let bump = tournament_pool.bump;
+
+        let tournament_seeds = &[
+            SEED_TOURNAMENT_POOL,
+            creator_key.as_ref(),
+            tournament_id_bytes,
+            token_type_seed.as_ref(),
+            &[bump],
+        ];
+        let signer_seeds: &[&[&[u8]]] = &[tournament_seeds];
+
+        // Distribute based on token type
+        match tournament_pool.token_type {
+            TokenType::SOL => {
+                let tournament_pool_info = tournament_pool.to_account_info();
+
+                // Prize pool transfer
+                if prize_amount > 0 {
+                    **tournament_pool_info.try_borrow_mut_lamports()? -= prize_amount;
+                    **ctx
+                        .accounts
+                        .prize_pool
+                        .to_account_info()
+                        .try_borrow_mut_lamports()? += prize_amount;
+                    ctx.accounts.prize_pool.total_funds += prize_amount;
+                }
+
+                // ✅ NEW: Developer share - direct to developer wallet (90% of revenue)
+                if developer_share > 0 {
+                    **tournament_pool_info.try_borrow_mut_lamports()? -= developer_share;
+                    **ctx
+                        .accounts
+                        .developer_wallet
+                        .try_borrow_mut_lamports()? += developer_share;
+                }
+
+                // ✅ NEW: Platform share - direct to platform wallet (10% of revenue)
+                if platform_share > 0 {
+                    **tournament_pool_info.try_borrow_mut_lamports()? -= platform_share;
+                    **ctx
+                        .accounts
+                        .platform_wallet
+                        .try_borrow_mut_lamports()? += platform_share;
+                }
+
+                // Staking rewards
+                if staking_amount > 0 {
+                    **tournament_pool_info.try_borrow_mut_lamports()? -= staking_amount;
+                    **ctx
+                        .accounts
+                        .reward_pool
+                        .to_account_info()
+                        .try_borrow_mut_lamports()? += staking_amount;
+                    ctx.accounts.reward_pool.total_funds += staking_amount;
+
+                    // Update staking pool accumulator
+                    let reward_pool_key = ctx.accounts.reward_pool.key();
+                    ctx.accounts.staking_pool.credit_rewards_from(
+                        &reward_pool_key,
+                        staking_amount,
+                        Clock::get()?.unix_timestamp,
+                    )?;
+                }
+
+                // Note: SOL cannot be "burned" - burn_amount stays in tournament pool or could be sent to a dead address
+                // For now, we'll send it to the platform wallet as additional revenue
+                if burn_amount > 0 {
+                    **tournament_pool_info.try_borrow_mut_lamports()? -= burn_amount;
+                    **ctx
+                        .accounts
+                        .platform_wallet
+                        .try_borrow_mut_lamports()? += burn_amount;
+                }
+
+                msg!("✅ SOL tournament revenue distributed");
+            }
+            TokenType::SPL => {
+                // Staking share must land in the bound reward pool's escrow
+                let reward_pool_key = ctx.accounts.reward_pool.key();
+                let (reward_escrow_pda, _bump) = Pubkey::find_program_address(
+                    &[SEED_REWARD_ESCROW, reward_pool_key.as_ref()],
+                    ctx.program_id,
+                );
+                require!(
+                    ctx.accounts.reward_escrow_account.key() == reward_escrow_pda,
+                    RewardError::InvalidEscrowAccount
+                );
+
+                let mint_decimals = {
+                    let mint_data = ctx.accounts.mint.try_borrow_data()?;
+                    let mint = Mint::try_deserialize(&mut &mint_data[..])?;
+                    mint.decimals
+                };
+
+                // Prize pool transfer
+                if prize_amount > 0 {
+                    let prize_pool = &mut ctx.accounts.prize_pool;
+                    token_2022::transfer_checked(
+                        CpiContext::new_with_signer(
+                            ctx.accounts.token_program.to_account_info(),
+                            TransferChecked {
+                                from: ctx.accounts.tournament_escrow_account.to_account_info(),
+                                to: ctx.accounts.prize_escrow_account.to_account_info(),
+                                mint: ctx.accounts.mint.to_account_info(),
+                                authority: tournament_pool.to_account_info(),
+                            },
+                            signer_seeds,
+                        ),
+                        prize_amount,
+                        mint_decimals,
+                    )?;
+                    prize_pool.total_funds += prize_amount;
+                }
+
+                // ✅ NEW: Developer share - direct to developer token account (90% of revenue)
+                if developer_share > 0 {
+                    token_2022::transfer_checked(
+                        CpiContext::new_with_signer(
+                            ctx.accounts.token_program.to_account_info(),
+                            TransferChecked {
+                                from: ctx.accounts.tournament_escrow_account.to_account_info(),
+                                to: ctx.accounts.developer_token_account.to_account_info(),
+                                mint: ctx.accounts.mint.to_account_info(),
+                                authority: tournament_pool.to_account_info(),
+                            },
+                            signer_seeds,
+                        ),
+                        developer_share,
+                        mint_decimals,
+                    )?;
+                }
+
+                // ✅ NEW: Platform share - direct to platform token account (10% of revenue)
+                if platform_share > 0 {
+                    token_2022::transfer_checked(
+                        CpiContext::new_with_signer(
+                            ctx.accounts.token_program.to_account_info(),
+                            TransferChecked {
+                                from: ctx.accounts.tournament_escrow_account.to_account_info(),
+                                to: ctx.accounts.platform_token_account.to_account_info(),
+                                mint: ctx.accounts.mint.to_account_info(),
+                                authority: tournament_pool.to_account_info(),
+                            },
+                            signer_seeds,
+                        ),
+                        platform_share,
+                        mint_decimals,
+                    )?;
+                }
+
+                // Staking rewards
+                if staking_amount > 0 {
+                    token_2022::transfer_checked(
+                        CpiContext::new_with_signer(
+                            ctx.accounts.token_program.to_account_info(),
+                            TransferChecked {
+                                from: ctx.accounts.tournament_escrow_account.to_account_info(),
+                                to: ctx.accounts.reward_escrow_account.to_account_info(),
+                                mint: ctx.accounts.mint.to_account_info(),
+                                authority: tournament_pool.to_account_info(),
+                            },
+                            signer_seeds,
+                        ),
+                        staking_amount,
+                        mint_decimals,
+                    )?;
+                    ctx.accounts.reward_pool.total_funds += staking_amount;
+
+                    let reward_pool_key = ctx.accounts.reward_pool.key();
+                    ctx.accounts.staking_pool.credit_rewards_from(
+                        &reward_pool_key,
+                        staking_amount,
+                        Clock::get()?.unix_timestamp,
+                    )?;
+                }
+
+                // Burn tokens
+                if burn_amount > 0 {
+                    token_2022::burn(
+                        CpiContext::new_with_signer(
+                            ctx.accounts.token_program.to_account_info(),
+                            Burn {
+                                mint: ctx.accounts.mint.to_account_info(),
+                                from: ctx.accounts.tournament_escrow_account.to_account_info(),
+                                authority: tournament_pool.to_account_info(),
+                            },
+                            signer_seeds,
+                        ),
+                        burn_amount,
+                    )?;
+                }
+
+                msg!("✅ SPL tournament revenue distributed");
+            }
+        }
+
+        // Update tournament pool state
+        tournament_pool.is_active = false;
+        tournament_pool.total_funds = 0;
+
+        msg!(
+            "✅ Tournament revenue distributed - Prize: {}, Developer (90%): {}, Platform (10%): {}, Staking: {}, Burn: {}",
+            prize_amount,
+            developer_share,
+            platform_share,
+            staking_amount,
+            burn_amount
+        );
+
+        Ok(())
+    }
+    /// Distribute prizes to tournament winners
+    /// Only callable by tournament creator
+    pub fn distribute_tournament_prizes(
+        ctx: Context<DistributeTournamentPrizes>,
+        tournament_id: String,
+    ) -> Result<()> {
+        // Fixed percentages for the top 3 positions
+        const PERCENTAGES: [u8; 3] = [50, 30, 20]; // 1st: 50%, 2nd: 30%, 3rd: 20%
+
+        let prize_pool = &mut ctx.accounts.prize_pool;
+
+        // Convert tournament_id to fixed-size bytes for comparison
+        let mut tournament_id_bytes = [0u8; 32];
+        let id_bytes = tournament_id.as_bytes();
+        let len = id_bytes.len().min(32);
+        tournament_id_bytes[..len].copy_from_slice(&id_bytes[..len]);
+
+        // Verify tournament ID matches
+        require!(
+            prize_pool.tournament_id == tournament_id_bytes,
+            TournamentError::Unauthorized
+        );
+
+        // Ensure prize pool hasn't been distributed yet
+        require!(!prize_pool.distributed, TournamentError::AlreadyDistributed);
+
+        // Ensure there are funds to distribute
+        require!(
+            prize_pool.total_funds > 0,
+            TournamentError::InsufficientFunds
+        );
+
+        let total_prize_pool = prize_pool.total_funds;
+
+        // Calculate prize amounts for each winner
+        let first_place_amount = (total_prize_pool as u128)
+            .saturating_mul(PERCENTAGES[0] as u128)
+            .checked_div(100)
+            .ok_or(TournamentError::MathOverflow)? as u64;
+
+        let second_place_amount = (total_prize_pool as u128)
+            .saturating_mul(PERCENTAGES[1] as u128)
+            .checked_div(100)
+            .ok_or(TournamentError::MathOverflow)? as u64;
+
+        let third_place_amount = (total_prize_pool as u128)
+            .saturating_mul(PERCENTAGES[2] as u128)
+            .checked_div(100)
+            .ok_or(TournamentError::MathOverflow)? as u64;
+
+        // Distribute based on token type
+        match prize_pool.token_type {
+            TokenType::SOL => {
+                // SOL PRIZE DISTRIBUTION via direct lamport manipulation
+                let prize_pool_info = prize_pool.to_account_info();
+
+                // Transfer to 1st place
+                if first_place_amount > 0 {
+                    **prize_pool_info.try_borrow_mut_lamports()? -= first_place_amount;
+                    **ctx.accounts.first_place_winner.try_borrow_mut_lamports()? +=
+                        first_place_amount;
+                }
+
+                // Transfer to 2nd place
+                if second_place_amount > 0 {
+                    **prize_pool_info.try_borrow_mut_lamports()? -= second_place_amount;
+                    **ctx.accounts.second_place_winner.try_borrow_mut_lamports()? +=
+                        second_place_amount;
+                }
+
+                // Transfer to 3rd place
+                if third_place_amount > 0 {
+                    **prize_pool_info.try_borrow_mut_lamports()? -= third_place_amount;
+                    **ctx.accounts.third_place_winner.try_borrow_mut_lamports()? +=
+                        third_place_amount;
+                }
+
+                msg!("✅ SOL tournament prizes distributed");
+            }
+            TokenType::SPL => {
+                // SPL TOKEN PRIZE DISTRIBUTION
+                let mint_decimals = {
+                    let mint_data = ctx.accounts.mint.try_borrow_data()?;
+                    let mint = Mint::try_deserialize(&mut &mint_data[..])?;
+                    mint.decimals
+                };
+
+                let tournament_pool_key = prize_pool.tournament_pool;
+                let bump = prize_pool.bump;
+                let signer_seeds: &[&[&[u8]]] =
+                    &[&[SEED_PRIZE_POOL, tournament_pool_key.as_ref(), &[bump]]];
+
+                // Transfer to 1st place
+                if first_place_amount > 0 {
+                    token_2022::transfer_checked(
+                        CpiContext::new_with_signer(
+                            ctx.accounts.token_program.to_account_info(),
+                            TransferChecked {
+                                from: ctx.accounts.prize_escrow_account.to_account_info(),
+                                to: ctx.accounts.first_place_token_account.to_account_info(),
+                                mint: ctx.accounts.mint.to_account_info(),
+                                authority: prize_pool.to_account_info(),
+                            },
+                            signer_seeds,
+                        ),
+                        first_place_amount,
+                        mint_decimals,
+                    )?;
+                }
+
+                // Transfer to 2nd place
+                if second_place_amount > 0 {
+                    token_2022::transfer_checked(
+                        CpiContext::new_with_signer(
+                            ctx.accounts.token_program.to_account_info(),
+                            TransferChecked {
+                                from: ctx.accounts.prize_escrow_account.to_account_info(),
+                                to: ctx.accounts.second_place_token_account.to_account_info(),
+                                mint: ctx.accounts.mint.to_account_info(),
+                                authority: prize_pool.to_account_info(),
+                            },
+                            signer_seeds,
+                        ),
+                        second_place_amount,
+                        mint_decimals,
+                    )?;
+                }
+
+                // Transfer to 3rd place
+                if third_place_amount > 0 {
+                    token_2022::transfer_checked(
+                        CpiContext::new_with_signer(
+                            ctx.accounts.token_program.to_account_info(),
+                            TransferChecked {
+                                from: ctx.accounts.prize_escrow_account.to_account_info(),
+                                to: ctx.accounts.third_place_token_account.to_account_info(),
+                                mint: ctx.accounts.mint.to_account_info(),
+                                authority: prize_pool.to_account_info(),
+                            },
+                            signer_seeds,
+                        ),
+                        third_place_amount,
+                        mint_decimals,
+                    )?;
+                }
+
+                msg!("✅ SPL tournament prizes distributed");
+            }
+        }
+
+        // Mark prize pool as distributed
+        prize_pool.distributed = true;
+        prize_pool.total_funds = 0;
+
+        msg!(
+            "✅ Tournament prizes distributed: 1st: {}, 2nd: {}, 3rd: {}",
+            first_place_amount,
+            second_place_amount,
+            third_place_amount
+        );
+
+        Ok(())
+    }
+
+}
+
+// ==============================
+// ACCOUNT CONTEXTS
+// ==============================
+
+// ==============================
+// PLATFORM CONFIGURATION
+// =============================='
+#[derive(Accounts)]
+pub struct InitializePlatformConfig<'info> {
+    #[account(
+        init,
+        payer = super_admin,
+        space = PlatformConfig::LEN,
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// CHECK: Platform wallet to receive revenue share
+    pub platform_wallet: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub super_admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePlatformConfig<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump,
+        constraint = platform_config.has_role(&authority.key(), role_account.as_deref(), ROLE_FEE_MANAGER) @ PlatformError::Unauthorized,
+        constraint = platform_config.admin_council == Pubkey::default() @ PlatformError::CouncilGoverned,
+        constraint = platform_config.governance_staking_pool == Pubkey::default() @ PlatformError::GovernanceControlled
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [SEED_ROLE, authority.key().as_ref()],
+        bump = role_account.bump
+    )]
+    pub role_account: Option<Account<'info, RoleAccount>>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateDistributionBounds<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump,
+        constraint = platform_config.has_role(&authority.key(), role_account.as_deref(), ROLE_FEE_MANAGER) @ PlatformError::Unauthorized,
+        constraint = platform_config.governance_staking_pool == Pubkey::default() @ PlatformError::GovernanceControlled
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [SEED_ROLE, authority.key().as_ref()],
+        bump = role_account.bump
+    )]
+    pub role_account: Option<Account<'info, RoleAccount>>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetPauseFlags<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump,
+        constraint = platform_config.has_role(&authority.key(), role_account.as_deref(), ROLE_PAUSER) @ PlatformError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [SEED_ROLE, authority.key().as_ref()],
+        bump = role_account.bump
+    )]
+    pub role_account: Option<Account<'info, RoleAccount>>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfigChangeDelay<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump,
+        constraint = platform_config.super_admin == super_admin.key() @ PlatformError::Unauthorized,
+        constraint = platform_config.admin_council == Pubkey::default() @ PlatformError::CouncilGoverned
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub super_admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteQueuedConfigChange<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
+
+#[derive(Accounts)]
+pub struct CancelQueuedConfigChange<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump,
+        constraint = platform_config.has_role(&authority.key(), role_account.as_deref(), ROLE_FEE_MANAGER) @ PlatformError::Unauthorized,
+        constraint = platform_config.admin_council == Pubkey::default() @ PlatformError::CouncilGoverned
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [SEED_ROLE, authority.key().as_ref()],
+        bump = role_account.bump
+    )]
+    pub role_account: Option<Account<'info, RoleAccount>>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ProposePlatformWallet<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump,
+        constraint = platform_config.has_role(&authority.key(), role_account.as_deref(), ROLE_TREASURY) @ PlatformError::Unauthorized,
+        constraint = platform_config.admin_council == Pubkey::default() @ PlatformError::CouncilGoverned
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// CHECK: Proposed platform wallet - must sign `accept_platform_wallet`
+    pub new_platform_wallet: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [SEED_ROLE, authority.key().as_ref()],
+        bump = role_account.bump
+    )]
+    pub role_account: Option<Account<'info, RoleAccount>>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptPlatformWallet<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump,
+        constraint = platform_config.pending_platform_wallet != Pubkey::default() @ PlatformError::NoPendingPlatformWallet,
+        constraint = platform_config.pending_platform_wallet == new_platform_wallet.key() @ PlatformError::NotPendingPlatformWallet
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub new_platform_wallet: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelPlatformWalletProposal<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump,
+        constraint = platform_config.has_role(&authority.key(), role_account.as_deref(), ROLE_TREASURY) @ PlatformError::Unauthorized,
+        constraint = platform_config.admin_council == Pubkey::default() @ PlatformError::CouncilGoverned
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [SEED_ROLE, authority.key().as_ref()],
+        bump = role_account.bump
+    )]
+    pub role_account: Option<Account<'info, RoleAccount>>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeSuperAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump,
+        constraint = platform_config.super_admin == super_admin.key() @ PlatformError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// CHECK: Proposed super admin - must sign `accept_super_admin`
+    pub new_super_admin: UncheckedAccount<'info>,
+
+    pub super_admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptSuperAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump,
+        constraint = platform_config.pending_super_admin != Pubkey::default() @ PlatformError::NoPendingSuperAdmin,
+        constraint = platform_config.pending_super_admin == new_super_admin.key() @ PlatformError::NotPendingSuperAdmin
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub new_super_admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelSuperAdminProposal<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump,
+        constraint = platform_config.super_admin == super_admin.key() @ PlatformError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub super_admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateDeveloperOnboardingFee<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump,
+        constraint = platform_config.has_role(&authority.key(), role_account.as_deref(), ROLE_FEE_MANAGER) @ PlatformError::Unauthorized,
+        constraint = platform_config.is_initialized @ PlatformError::NotInitialized,
+        constraint = platform_config.admin_council == Pubkey::default() @ PlatformError::CouncilGoverned
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [SEED_ROLE, authority.key().as_ref()],
+        bump = role_account.bump
+    )]
+    pub role_account: Option<Account<'info, RoleAccount>>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct PayDeveloperOnboardingFee<'info> {
+    #[account(mut)]
+    pub developer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump,
+        constraint = platform_config.is_initialized @ PlatformError::NotInitialized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// CHECK: Platform wallet to receive fee - validated against config
+    #[account(
+        mut,
+        constraint = platform_wallet.key() == platform_config.platform_wallet @ PlatformError::InvalidPlatformWallet
+    )]
+    pub platform_wallet: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = developer,
+        space = DeveloperOnboardingRecord::LEN,
+        seeds = [SEED_DEVELOPER_ONBOARDING, developer.key().as_ref()],
+        bump
+    )]
+    pub onboarding_record: Account<'info, DeveloperOnboardingRecord>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseDeveloperOnboardingRecord<'info> {
+    /// Super admin or developer moderator closing the record
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Role of the authority, if not the super admin
+    #[account(
+        seeds = [SEED_ROLE, authority.key().as_ref()],
+        bump = role_account.bump
+    )]
+    pub role_account: Option<Account<'info, RoleAccount>>,
+
+    /// Platform configuration - verify ROLE_DEVELOPER_MODERATOR authority
+    #[account(
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump,
+        constraint = platform_config.has_role(&authority.key(), role_account.as_deref(), ROLE_DEVELOPER_MODERATOR) @ PlatformError::Unauthorized,
+        constraint = platform_config.is_initialized @ PlatformError::NotInitialized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// The developer whose record is being closed
+    /// CHECK: This is just used to derive the PDA, not modified
+    pub developer: UncheckedAccount<'info>,
+
+    /// The onboarding record to close
+    /// Rent will be returned to rent_recipient
+    #[account(
+        mut,
+        seeds = [SEED_DEVELOPER_ONBOARDING, developer.key().as_ref()],
+        bump = onboarding_record.bump,
+        close = rent_recipient,
+        constraint = onboarding_record.developer == developer.key() @ OnboardingError::InvalidDeveloper
+    )]
+    pub onboarding_record: Account<'info, DeveloperOnboardingRecord>,
+
+    /// Account to receive the rent from closed account
+    /// Can be the authority or the developer
+    /// CHECK: Any account can receive rent
+    #[account(mut)]
+    pub rent_recipient: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// ==============================
+// ROLE REGISTRY
+// ==============================
+
+#[derive(Accounts)]
+#[instruction(holder: Pubkey)]
+pub struct GrantRole<'info> {
+    #[account(
+        init_if_needed,
+        payer = super_admin,
+        space = RoleAccount::LEN,
+        seeds = [SEED_ROLE, holder.as_ref()],
+        bump
+    )]
+    pub role_account: Account<'info, RoleAccount>,
+
+    #[account(
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump,
+        constraint = platform_config.super_admin == super_admin.key() @ PlatformError::Unauthorized,
+        constraint = platform_config.is_initialized @ PlatformError::NotInitialized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub super_admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(holder: Pubkey)]
+pub struct RevokeRole<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_ROLE, holder.as_ref()],
+        bump = role_account.bump
+    )]
+    pub role_account: Account<'info, RoleAccount>,
+
+    #[account(
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump,
+        constraint = platform_config.super_admin == super_admin.key() @ PlatformError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub super_admin: Signer<'info>,
+}
+
+// ==============================
+// ADMIN COUNCIL
+// ==============================
+
+#[derive(Accounts)]
+pub struct InitializeAdminCouncil<'info> {
+    #[account(
+        init,
+        payer = super_admin,
+        space = AdminCouncil::LEN,
+        seeds = [SEED_ADMIN_COUNCIL],
+        bump
+    )]
+    pub admin_council: Account<'info, AdminCouncil>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump,
+        constraint = platform_config.super_admin == super_admin.key() @ PlatformError::Unauthorized,
+        constraint = platform_config.is_initialized @ PlatformError::NotInitialized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub super_admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CreateCouncilProposal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_ADMIN_COUNCIL],
+        bump = admin_council.bump
+    )]
+    pub admin_council: Account<'info, AdminCouncil>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = CouncilProposal::LEN,
+        seeds = [SEED_COUNCIL_PROPOSAL, admin_council.key().as_ref(), &admin_council.proposal_count.to_le_bytes()],
+        bump
+    )]
+    pub council_proposal: Account<'info, CouncilProposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ApproveCouncilProposal<'info> {
+    pub member: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_ADMIN_COUNCIL],
+        bump = admin_council.bump
+    )]
+    pub admin_council: Account<'info, AdminCouncil>,
+
+    #[account(
+        mut,
+        seeds = [SEED_COUNCIL_PROPOSAL, admin_council.key().as_ref(), &proposal_id.to_le_bytes()],
+        bump = council_proposal.bump
+    )]
+    pub council_proposal: Account<'info, CouncilProposal>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ExecuteCouncilProposal<'info> {
+    #[account(
+        constraint = admin_council.member_index(&member.key()).is_some() @ CouncilError::NotCouncilMember
+    )]
+    pub member: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_ADMIN_COUNCIL],
+        bump = admin_council.bump
+    )]
+    pub admin_council: Account<'info, AdminCouncil>,
+
+    #[account(
+        mut,
+        seeds = [SEED_COUNCIL_PROPOSAL, admin_council.key().as_ref(), &proposal_id.to_le_bytes()],
+        bump = council_proposal.bump
+    )]
+    pub council_proposal: Account<'info, CouncilProposal>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump,
+        constraint = platform_config.admin_council == admin_council.key() @ CouncilError::InvalidCouncil
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
+
+// ==============================
+// STAKE-WEIGHTED GOVERNANCE
+// ==============================
+
+#[derive(Accounts)]
+pub struct SetCanonicalPools<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump,
+        constraint = platform_config.super_admin == super_admin.key() @ PlatformError::Unauthorized,
+        constraint = platform_config.is_initialized @ PlatformError::NotInitialized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), staking_pool.seed_mint().as_ref(), &[staking_pool.token_type as u8]],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        seeds = [SEED_REWARD_POOL, reward_pool.admin.as_ref(), reward_pool.seed_mint().as_ref(), &[reward_pool.token_type as u8]],
+        bump = reward_pool.bump,
+        constraint = staking_pool.pays_from(&reward_pool.key()) @ PlatformError::InvalidPoolBinding
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    pub super_admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureGovernance<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump,
+        constraint = platform_config.super_admin == super_admin.key() @ PlatformError::Unauthorized,
+        constraint = platform_config.is_initialized @ PlatformError::NotInitialized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), staking_pool.seed_mint().as_ref(), &[staking_pool.token_type as u8]],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    pub super_admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(position_id: u64)]
+pub struct CreateGovernanceProposal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump,
+        constraint = platform_config.governance_staking_pool != Pubkey::default() @ GovernanceError::NotConfigured
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), staking_pool.seed_mint().as_ref(), &[staking_pool.token_type as u8]],
+        bump = staking_pool.bump,
+        address = platform_config.governance_staking_pool @ GovernanceError::InvalidStakingPool
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    // Last completed epoch of the governance pool
+    #[account(
+        seeds = [SEED_EPOCH_SNAPSHOT, staking_pool.key().as_ref(), &staking_pool.epoch_index.saturating_sub(1).to_le_bytes()],
+        bump = epoch_snapshot.bump,
+        constraint = staking_pool.epoch_index > 0 @ GovernanceError::NoEpochSnapshot
+    )]
+    pub epoch_snapshot: Account<'info, EpochSnapshot>,
+
+    // Proposers must hold a staked position in the governance pool
+    #[account(
+        seeds = [SEED_USER_STAKING, staking_pool.key().as_ref(), proposer.key().as_ref(), position_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = user_staking_account.owner == proposer.key() @ StakingError::Unauthorized,
+        constraint = user_staking_account.weight > 0 @ GovernanceError::NoVotingWeight
+    )]
+    pub user_staking_account: Account<'info, UserStakingAccount>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = GovernanceProposal::LEN,
+        seeds = [SEED_GOVERNANCE_PROPOSAL, &platform_config.governance_proposal_count.to_le_bytes()],
+        bump
+    )]
+    pub governance_proposal: Account<'info, GovernanceProposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64, position_id: u64)]
+pub struct CastVote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_GOVERNANCE_PROPOSAL, &proposal_id.to_le_bytes()],
+        bump = governance_proposal.bump
+    )]
+    pub governance_proposal: Account<'info, GovernanceProposal>,
+
+    #[account(
+        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), staking_pool.seed_mint().as_ref(), &[staking_pool.token_type as u8]],
+        bump = staking_pool.bump,
+        address = governance_proposal.staking_pool @ GovernanceError::InvalidStakingPool
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        seeds = [SEED_USER_STAKING, staking_pool.key().as_ref(), voter.key().as_ref(), position_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = user_staking_account.owner == voter.key() @ StakingError::Unauthorized
+    )]
+    pub user_staking_account: Account<'info, UserStakingAccount>,
+
+    // One vote per position per proposal
+    #[account(
+        init,
+        payer = voter,
+        space = VoteRecord::LEN,
+        seeds = [SEED_VOTE_RECORD, governance_proposal.key().as_ref(), user_staking_account.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ExecuteGovernanceProposal<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_GOVERNANCE_PROPOSAL, &proposal_id.to_le_bytes()],
+        bump = governance_proposal.bump
+    )]
+    pub governance_proposal: Account<'info, GovernanceProposal>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct CancelGovernanceProposal<'info> {
+    pub proposer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_GOVERNANCE_PROPOSAL, &proposal_id.to_le_bytes()],
+        bump = governance_proposal.bump,
+        constraint = governance_proposal.proposer == proposer.key() @ GovernanceError::NotProposer
+    )]
+    pub governance_proposal: Account<'info, GovernanceProposal>,
+}
+
+// ==============================
+// STAKING POOL INITIALIZATION
+// ==============================
+
+#[derive(Accounts)]
+#[instruction(token_type: TokenType)]
+pub struct InitializeAccounts<'info> {
+    /// CHECK: For SPL, must be valid mint. For SOL, we pass SystemProgram.programId as dummy
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = StakingPool::LEN,
+        seeds = [SEED_STAKING_POOL, admin.key().as_ref(), pool_seed_mint(token_type, &mint.key()).as_ref(), &[token_type as u8]],
+        bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = PoolRegistry::LEN,
+        seeds = [SEED_POOL_REGISTRY],
+        bump
+    )]
+    pub pool_registry: Account<'info, PoolRegistry>,
+
+    #[account(
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// CHECK: For SPL, this must be a token account. For SOL, can be any account (we pass SystemProgram)
+    #[account(mut)]
+    pub pool_escrow_account: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Token program - only validated when token_type is SPL
+    pub token_program: UncheckedAccount<'info>,
+}
+
+// ==============================
+// LOCK TIER REGISTRY
+// ==============================
+
+#[derive(Accounts)]
+pub struct InitializeLockTiers<'info> {
+    #[account(
+        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), staking_pool.seed_mint().as_ref(), &[staking_pool.token_type as u8]],
+        bump = staking_pool.bump,
+        constraint = staking_pool.admin == admin.key() @ StakingError::Unauthorized,
+        constraint = !staking_pool.liquid @ StakingError::LiquidPool
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = LockTierRegistry::LEN,
+        seeds = [SEED_LOCK_TIERS, staking_pool.key().as_ref()],
+        bump
+    )]
+    pub lock_tiers: Account<'info, LockTierRegistry>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ManageLockTiers<'info> {
+    #[account(
+        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), staking_pool.seed_mint().as_ref(), &[staking_pool.token_type as u8]],
+        bump = staking_pool.bump,
+        constraint = staking_pool.admin == admin.key() @ StakingError::Unauthorized
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        mut,
+        seeds = [SEED_LOCK_TIERS, staking_pool.key().as_ref()],
+        bump = lock_tiers.bump
+    )]
+    pub lock_tiers: Account<'info, LockTierRegistry>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureStakingPool<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), staking_pool.seed_mint().as_ref(), &[staking_pool.token_type as u8]],
+        bump = staking_pool.bump,
+        constraint = staking_pool.admin == admin.key() @ StakingError::Unauthorized
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AdvanceEpoch<'info> {
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), staking_pool.seed_mint().as_ref(), &[staking_pool.token_type as u8]],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        init,
+        payer = cranker,
+        space = EpochSnapshot::LEN,
+        seeds = [SEED_EPOCH_SNAPSHOT, staking_pool.key().as_ref(), &staking_pool.epoch_index.to_le_bytes()],
+        bump
+    )]
+    pub epoch_snapshot: Account<'info, EpochSnapshot>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(position_id: u64)]
+pub struct PositionWeightAtEpoch<'info> {
+    /// CHECK: Owner of the position; only used for seeds
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), staking_pool.seed_mint().as_ref(), &[staking_pool.token_type as u8]],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        seeds = [SEED_USER_STAKING, staking_pool.key().as_ref(), owner.key().as_ref(), position_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub user_staking_account: Account<'info, UserStakingAccount>,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimUndistributedRewards<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), staking_pool.seed_mint().as_ref(), &[staking_pool.token_type as u8]],
+        bump = staking_pool.bump,
+        constraint = staking_pool.admin == admin.key() @ StakingError::Unauthorized,
+        constraint = staking_pool.retired @ StakingError::PoolNotRetired,
+        constraint = staking_pool.total_weight == 0 @ StakingError::PoolHasStakers
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        mut,
+        seeds = [SEED_REWARD_POOL, reward_pool.admin.as_ref(), reward_pool.seed_mint().as_ref(), &[reward_pool.token_type as u8]],
+        bump = reward_pool.bump,
+        constraint = reward_pool.key() == staking_pool.reward_pool @ StakingError::InvalidRewardPool
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    /// CHECK: For SPL, the reward escrow. For SOL, dummy (SystemProgram).
+    #[account(mut)]
+    pub reward_escrow_account: UncheckedAccount<'info>,
+
+    /// CHECK: For SPL, admin's token account for the reward mint. For SOL, dummy (SystemProgram).
+    #[account(mut)]
+    pub admin_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: For SPL, the reward pool mint. For SOL, dummy (SystemProgram).
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: Token program - only used for SPL
+    pub token_program: UncheckedAccount<'info>,
+}
+
+// ==============================
+// STAKING OPERATIONS
+// ==============================
+
+#[derive(Accounts)]
+#[instruction(position_id: u64)]
+pub struct Stake<'info> {
+    // Funder: pays for the stake and the accounts
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// CHECK: Owner of the new position; equals `user` for a plain `stake`
+    pub beneficiary: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), staking_pool.seed_mint().as_ref(), &[staking_pool.token_type as u8]],
+        bump = staking_pool.bump,
+        constraint = !staking_pool.retired @ StakingError::PoolRetired,
+        constraint = !staking_pool.liquid @ StakingError::LiquidPool,
+        constraint = staking_pool.reward_pool != Pubkey::default() @ StakingError::RewardPoolNotLinked
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_STAKE) @ PlatformError::Paused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [SEED_LOCK_TIERS, staking_pool.key().as_ref()],
+        bump = lock_tiers.bump
+    )]
+    pub lock_tiers: Account<'info, LockTierRegistry>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = UserStakeIndex::LEN,
+        seeds = [SEED_USER_STAKE_INDEX, staking_pool.key().as_ref(), beneficiary.key().as_ref()],
+        bump
+    )]
+    pub stake_index: Account<'info, UserStakeIndex>,
+
+    #[account(
+        init,
+        payer = user,
+        space = UserStakingAccount::LEN,
+        seeds = [SEED_USER_STAKING, staking_pool.key().as_ref(), beneficiary.key().as_ref(), position_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub user_staking_account: Account<'info, UserStakingAccount>,
+
+    /// CHECK: For SPL, this is the funder's token account. For SOL, dummy (SystemProgram).
+    pub user_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: For SPL, this is staking escrow. For SOL, this is the SOL vault (no data).
+    /// Must be writable for both token types.
+    #[account(mut)]
+    pub pool_escrow_account: UncheckedAccount<'info>,
+
+    /// CHECK: For SPL, must be valid mint. For SOL, dummy (SystemProgram).
+    pub mint: UncheckedAccount<'info>,
+
+    /// CHECK: Token program - only used for SPL
+    pub token_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetDelegatedStakeMinimum<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), staking_pool.seed_mint().as_ref(), &[staking_pool.token_type as u8]],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = UserStakeIndex::LEN,
+        seeds = [SEED_USER_STAKE_INDEX, staking_pool.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub stake_index: Account<'info, UserStakeIndex>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(position_id: u64)]
+pub struct ExtendLock<'info> {
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), staking_pool.seed_mint().as_ref(), &[staking_pool.token_type as u8]],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_STAKE) @ PlatformError::Paused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        seeds = [SEED_LOCK_TIERS, staking_pool.key().as_ref()],
+        bump = lock_tiers.bump
+    )]
+    pub lock_tiers: Account<'info, LockTierRegistry>,
+
+    #[account(
+        mut,
+        seeds = [SEED_USER_STAKING, staking_pool.key().as_ref(), user.key().as_ref(), position_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = user_staking_account.owner == user.key() @ StakingError::Unauthorized,
+        constraint = user_staking_account.staked_amount > 0 @ StakingError::InsufficientStakedBalance
+    )]
+    pub user_staking_account: Account<'info, UserStakingAccount>,
+}
+
+// ==============================
+// UNSTAKING OPERATIONS
+// ==============================
+
+#[derive(Accounts)]
+#[instruction(position_id: u64)]
+pub struct Unstake<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), staking_pool.seed_mint().as_ref(), &[staking_pool.token_type as u8]],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_UNSTAKE) @ PlatformError::Paused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
 
-        let total_prize_pool = prize_pool.total_funds;
+    #[account(
+        mut,
+        seeds = [SEED_USER_STAKE_INDEX, staking_pool.key().as_ref(), user.key().as_ref()],
+        bump = stake_index.bump
+    )]
+    pub stake_index: Account<'info, UserStakeIndex>,
 
-        // Calculate prize amounts for each winner
-        let first_place_amount = (total_prize_pool as u128)
-            .saturating_mul(PERCENTAGES[0] as u128)
-            .checked_div(100)
-            .ok_or(TournamentError::MathOverflow)? as u64;
+    #[account(
+        mut,
+        seeds = [SEED_USER_STAKING, staking_pool.key().as_ref(), user.key().as_ref(), position_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = user_staking_account.owner == user.key() @ StakingError::Unauthorized
+    )]
+    pub user_staking_account: Account<'info, UserStakingAccount>,
 
-        let second_place_amount = (total_prize_pool as u128)
-            .saturating_mul(PERCENTAGES[1] as u128)
-            .checked_div(100)
-            .ok_or(TournamentError::MathOverflow)? as u64;
+    // Pays out accrued rewards and receives SOL early unstake penalties
+    #[account(
+        mut,
+        seeds = [SEED_REWARD_POOL, reward_pool.admin.as_ref(), reward_pool.seed_mint().as_ref(), &[reward_pool.token_type as u8]],
+        bump = reward_pool.bump,
+        constraint = reward_pool.key() == staking_pool.reward_pool @ StakingError::InvalidRewardPool
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
 
-        let third_place_amount = (total_prize_pool as u128)
-            .saturating_mul(PERCENTAGES[2] as u128)
-            .checked_div(100)
-            .ok_or(TournamentError::MathOverflow)? as u64;
+    /// CHECK: For SPL, the reward escrow. For SOL, dummy (SystemProgram).
+    #[account(mut)]
+    pub reward_escrow_account: UncheckedAccount<'info>,
 
-        // Distribute based on token type
-        match prize_pool.token_type {
-            TokenType::SOL => {
-                // SOL PRIZE DISTRIBUTION via direct lamport manipulation
-                let prize_pool_info = prize_pool.to_account_info();
+    /// CHECK: For SPL, user's token account for the reward mint. For SOL, dummy (SystemProgram).
+    #[account(mut)]
+    pub user_reward_token_account: UncheckedAccount<'info>,
 
-                // Transfer to 1st place
-                if first_place_amount > 0 {
-                    **prize_pool_info.try_borrow_mut_lamports()? -= first_place_amount;
-                    **ctx.accounts.first_place_winner.try_borrow_mut_lamports()? +=
-                        first_place_amount;
-                }
+    /// CHECK: For SPL, the reward pool mint. For SOL, dummy (SystemProgram).
+    pub reward_mint: UncheckedAccount<'info>,
 
-                // Transfer to 2nd place
-                if second_place_amount > 0 {
-                    **prize_pool_info.try_borrow_mut_lamports()? -= second_place_amount;
-                    **ctx.accounts.second_place_winner.try_borrow_mut_lamports()? +=
-                        second_place_amount;
-                }
+    /// CHECK: For SPL, this is user's token account. For SOL, dummy (SystemProgram).
+    pub user_token_account: UncheckedAccount<'info>,
 
-                // Transfer to 3rd place
-                if third_place_amount > 0 {
-                    **prize_pool_info.try_borrow_mut_lamports()? -= third_place_amount;
-                    **ctx.accounts.third_place_winner.try_borrow_mut_lamports()? +=
-                        third_place_amount;
-                }
+    /// CHECK: For SPL, this is staking escrow. For SOL, this is the SOL vault (no data).
+    /// Must be writable for both token types.
+    #[account(mut)]
+    pub pool_escrow_account: UncheckedAccount<'info>,
 
-                msg!("✅ SOL tournament prizes distributed");
-            }
-            TokenType::SPL => {
-                // SPL TOKEN PRIZE DISTRIBUTION
-                let mint_decimals = {
-                    let mint_data = ctx.accounts.mint.try_borrow_data()?;
-                    let mint = Mint::try_deserialize(&mut &mint_data[..])?;
-                    mint.decimals
-                };
+    /// CHECK: For SPL, must be valid mint; writable so early unstake penalties can be burned. For SOL, dummy (SystemProgram).
+    #[account(mut)]
+    pub mint: UncheckedAccount<'info>,
 
-                let tournament_pool_key = prize_pool.tournament_pool;
-                let bump = prize_pool.bump;
-                let signer_seeds: &[&[&[u8]]] =
-                    &[&[SEED_PRIZE_POOL, tournament_pool_key.as_ref(), &[bump]]];
+    /// CHECK: Token program - only used for SPL
+    pub token_program: UncheckedAccount<'info>,
 
-                // Transfer to 1st place
-                if first_place_amount > 0 {
-                    token_2022::transfer_checked(
-                        CpiContext::new_with_signer(
-                            ctx.accounts.token_program.to_account_info(),
-                            TransferChecked {
-                                from: ctx.accounts.prize_escrow_account.to_account_info(),
-                                to: ctx.accounts.first_place_token_account.to_account_info(),
-                                mint: ctx.accounts.mint.to_account_info(),
-                                authority: prize_pool.to_account_info(),
-                            },
-                            signer_seeds,
-                        ),
-                        first_place_amount,
-                        mint_decimals,
-                    )?;
-                }
+    pub system_program: Program<'info, System>,
+}
 
-                // Transfer to 2nd place
-                if second_place_amount > 0 {
-                    token_2022::transfer_checked(
-                        CpiContext::new_with_signer(
-                            ctx.accounts.token_program.to_account_info(),
-                            TransferChecked {
-                                from: ctx.accounts.prize_escrow_account.to_account_info(),
-                                to: ctx.accounts.second_place_token_account.to_account_info(),
-                                mint: ctx.accounts.mint.to_account_info(),
-                                authority: prize_pool.to_account_info(),
-                            },
-                            signer_seeds,
-                        ),
-                        second_place_amount,
-                        mint_decimals,
-                    )?;
-                }
+#[derive(Accounts)]
+#[instruction(position_id: u64, ticket_id: u64)]
+pub struct RequestUnstake<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
 
-                // Transfer to 3rd place
-                if third_place_amount > 0 {
-                    token_2022::transfer_checked(
-                        CpiContext::new_with_signer(
-                            ctx.accounts.token_program.to_account_info(),
-                            TransferChecked {
-                                from: ctx.accounts.prize_escrow_account.to_account_info(),
-                                to: ctx.accounts.third_place_token_account.to_account_info(),
-                                mint: ctx.accounts.mint.to_account_info(),
-                                authority: prize_pool.to_account_info(),
-                            },
-                            signer_seeds,
-                        ),
-                        third_place_amount,
-                        mint_decimals,
-                    )?;
-                }
+    #[account(
+        mut,
+        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), staking_pool.seed_mint().as_ref(), &[staking_pool.token_type as u8]],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
 
-                msg!("✅ SPL tournament prizes distributed");
-            }
-        }
+    #[account(
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_UNSTAKE) @ PlatformError::Paused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
 
-        // Mark prize pool as distributed
-        prize_pool.distributed = true;
-        prize_pool.total_funds = 0;
+    #[account(
+        mut,
+        seeds = [SEED_USER_STAKE_INDEX, staking_pool.key().as_ref(), user.key().as_ref()],
+        bump = stake_index.bump
+    )]
+    pub stake_index: Account<'info, UserStakeIndex>,
 
-        msg!(
-            "✅ Tournament prizes distributed: 1st: {}, 2nd: {}, 3rd: {}",
-            first_place_amount,
-            second_place_amount,
-            third_place_amount
-        );
+    #[account(
+        mut,
+        seeds = [SEED_USER_STAKING, staking_pool.key().as_ref(), user.key().as_ref(), position_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = user_staking_account.owner == user.key() @ StakingError::Unauthorized
+    )]
+    pub user_staking_account: Account<'info, UserStakingAccount>,
 
-        Ok(())
-    }
+    #[account(
+        init,
+        payer = user,
+        space = UnbondingTicket::LEN,
+        seeds = [SEED_UNBONDING_TICKET, staking_pool.key().as_ref(), user.key().as_ref(), ticket_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub unbonding_ticket: Account<'info, UnbondingTicket>,
 
+    pub system_program: Program<'info, System>,
 }
 
-// ==============================
-// ACCOUNT CONTEXTS
-// ==============================
-
-// ==============================
-// PLATFORM CONFIGURATION
-// =============================='
 #[derive(Accounts)]
-pub struct InitializePlatformConfig<'info> {
+#[instruction(ticket_id: u64)]
+pub struct WithdrawUnbonded<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), staking_pool.seed_mint().as_ref(), &[staking_pool.token_type as u8]],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
     #[account(
-        init,
-        payer = super_admin,
-        space = PlatformConfig::LEN,
         seeds = [SEED_PLATFORM_CONFIG],
-        bump
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_UNSTAKE) @ PlatformError::Paused
     )]
     pub platform_config: Account<'info, PlatformConfig>,
 
-    /// CHECK: Platform wallet to receive revenue share
-    pub platform_wallet: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = user,
+        seeds = [SEED_UNBONDING_TICKET, staking_pool.key().as_ref(), user.key().as_ref(), ticket_id.to_le_bytes().as_ref()],
+        bump = unbonding_ticket.bump,
+        constraint = unbonding_ticket.owner == user.key() @ StakingError::Unauthorized
+    )]
+    pub unbonding_ticket: Account<'info, UnbondingTicket>,
+
+    /// CHECK: For SPL, this is user's token account. For SOL, dummy (SystemProgram).
+    #[account(mut)]
+    pub user_token_account: UncheckedAccount<'info>,
 
+    /// CHECK: For SPL, the staking escrow. For SOL, the SOL vault. Validated in logic.
     #[account(mut)]
-    pub super_admin: Signer<'info>,
+    pub pool_escrow_account: UncheckedAccount<'info>,
+
+    /// CHECK: For SPL, must be the staking mint. For SOL, dummy (SystemProgram).
+    #[account(
+        constraint = staking_pool.token_type == TokenType::SOL
+            || mint.key() == staking_pool.mint @ StakingError::InvalidEscrowAccount
+    )]
+    pub mint: UncheckedAccount<'info>,
+
+    /// CHECK: Token program - only used for SPL
+    pub token_program: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
+// ==============================
+// REWARD CLAIMING
+// ==============================
+
 #[derive(Accounts)]
-pub struct UpdatePlatformConfig<'info> {
+#[instruction(position_id: u64)]
+pub struct ClaimRewards<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
     #[account(
         mut,
+        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), staking_pool.seed_mint().as_ref(), &[staking_pool.token_type as u8]],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
         seeds = [SEED_PLATFORM_CONFIG],
         bump = platform_config.bump,
-        constraint = platform_config.super_admin == super_admin.key() @ PlatformError::Unauthorized
+        constraint = !platform_config.is_paused(PAUSE_CLAIM) @ PlatformError::Paused
     )]
     pub platform_config: Account<'info, PlatformConfig>,
 
-    pub super_admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [SEED_USER_STAKE_INDEX, staking_pool.key().as_ref(), user.key().as_ref()],
+        bump = stake_index.bump
+    )]
+    pub stake_index: Account<'info, UserStakeIndex>,
+
+    #[account(
+        mut,
+        seeds = [SEED_USER_STAKING, staking_pool.key().as_ref(), user.key().as_ref(), position_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = user_staking_account.owner == user.key() @ StakingError::Unauthorized
+    )]
+    pub user_staking_account: Account<'info, UserStakingAccount>,
+
+    #[account(
+        mut,
+        seeds = [SEED_REWARD_POOL, reward_pool.admin.as_ref(), reward_pool.seed_mint().as_ref(), &[reward_pool.token_type as u8]],
+        bump = reward_pool.bump,
+        constraint = reward_pool.key() == staking_pool.reward_pool @ StakingError::InvalidRewardPool
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    /// CHECK: Only used for SPL tokens
+    #[account(mut)]
+    pub user_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: Only used for SPL tokens
+    #[account(mut)]
+    pub reward_escrow_account: UncheckedAccount<'info>,
+
+    /// CHECK: For SPL, must be valid mint. For SOL, we pass SystemProgram.programId
+    pub mint: UncheckedAccount<'info>, // ✅ Change from InterfaceAccount to UncheckedAccount
+
+    /// CHECK: Token program - only used for SPL
+    pub token_program: UncheckedAccount<'info>, // ✅ Change from Program to UncheckedAccount
+
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct UpdatePlatformWallet<'info> {
+#[instruction(position_id: u64)]
+pub struct CompoundRewards<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
     #[account(
         mut,
-        seeds = [SEED_PLATFORM_CONFIG],
-        bump = platform_config.bump,
-        constraint = platform_config.super_admin == super_admin.key() @ PlatformError::Unauthorized
+        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), staking_pool.seed_mint().as_ref(), &[staking_pool.token_type as u8]],
+        bump = staking_pool.bump,
+        constraint = staking_pool.token_type == TokenType::SPL @ StakingError::CompoundNotSupported
     )]
-    pub platform_config: Account<'info, PlatformConfig>,
-
-    /// CHECK: New platform wallet address
-    pub new_platform_wallet: UncheckedAccount<'info>,
-
-    pub super_admin: Signer<'info>,
-}
+    pub staking_pool: Account<'info, StakingPool>,
 
-#[derive(Accounts)]
-pub struct TransferSuperAdmin<'info> {
+    // Compounding both claims and stakes, so either pause blocks it
     #[account(
-        mut,
         seeds = [SEED_PLATFORM_CONFIG],
         bump = platform_config.bump,
-        constraint = platform_config.super_admin == super_admin.key() @ PlatformError::Unauthorized
+        constraint = !platform_config.is_paused(PAUSE_STAKE | PAUSE_CLAIM) @ PlatformError::Paused
     )]
     pub platform_config: Account<'info, PlatformConfig>,
 
-    /// CHECK: New super admin address
-    pub new_super_admin: UncheckedAccount<'info>,
-
-    pub super_admin: Signer<'info>,
-}
-
-#[derive(Accounts)]
-pub struct UpdateDeveloperOnboardingFee<'info> {
     #[account(
         mut,
-        seeds = [SEED_PLATFORM_CONFIG],
-        bump = platform_config.bump,
-        constraint = platform_config.super_admin == super_admin.key() @ PlatformError::Unauthorized,
-        constraint = platform_config.is_initialized @ PlatformError::NotInitialized
+        seeds = [SEED_USER_STAKING, staking_pool.key().as_ref(), user.key().as_ref(), position_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = user_staking_account.owner == user.key() @ StakingError::Unauthorized,
+        constraint = user_staking_account.staked_amount > 0 @ StakingError::InsufficientStakedBalance
     )]
-    pub platform_config: Account<'info, PlatformConfig>,
-
-    pub super_admin: Signer<'info>,
-}
-
-#[derive(Accounts)]
-pub struct PayDeveloperOnboardingFee<'info> {
-    #[account(mut)]
-    pub developer: Signer<'info>,
+    pub user_staking_account: Account<'info, UserStakingAccount>,
 
     #[account(
-        seeds = [SEED_PLATFORM_CONFIG],
-        bump = platform_config.bump,
-        constraint = platform_config.is_initialized @ PlatformError::NotInitialized
+        mut,
+        seeds = [SEED_REWARD_POOL, reward_pool.admin.as_ref(), reward_pool.seed_mint().as_ref(), &[reward_pool.token_type as u8]],
+        bump = reward_pool.bump,
+        constraint = reward_pool.key() == staking_pool.reward_pool @ StakingError::InvalidRewardPool,
+        constraint = reward_pool.mint == staking_pool.mint @ StakingError::CompoundNotSupported
     )]
-    pub platform_config: Account<'info, PlatformConfig>,
+    pub reward_pool: Account<'info, RewardPool>,
 
-    /// CHECK: Platform wallet to receive fee - validated against config
+    /// CHECK: Reward escrow PDA, validated by seeds
     #[account(
         mut,
-        constraint = platform_wallet.key() == platform_config.platform_wallet @ PlatformError::InvalidPlatformWallet
+        seeds = [SEED_REWARD_ESCROW, reward_pool.key().as_ref()],
+        bump
     )]
-    pub platform_wallet: UncheckedAccount<'info>,
+    pub reward_escrow_account: UncheckedAccount<'info>,
 
+    /// CHECK: Staking escrow PDA, validated by seeds
     #[account(
-        init,
-        payer = developer,
-        space = DeveloperOnboardingRecord::LEN,
-        seeds = [SEED_DEVELOPER_ONBOARDING, developer.key().as_ref()],
+        mut,
+        seeds = [SEED_ESCROW, staking_pool.key().as_ref()],
         bump
     )]
-    pub onboarding_record: Account<'info, DeveloperOnboardingRecord>,
+    pub pool_escrow_account: UncheckedAccount<'info>,
 
-    pub system_program: Program<'info, System>,
+    /// CHECK: Staking (and reward) mint
+    #[account(constraint = mint.key() == staking_pool.mint @ StakingError::CompoundNotSupported)]
+    pub mint: UncheckedAccount<'info>,
+
+    /// CHECK: Token program
+    pub token_program: UncheckedAccount<'info>,
 }
 
+// ==============================
+// REWARD ACCRUING
+// ==============================
+
 #[derive(Accounts)]
-pub struct CloseDeveloperOnboardingRecord<'info> {
-    /// Super admin closing the record
+#[instruction(position_id: u64)]
+pub struct AccrueRewards<'info> {
     #[account(mut)]
-    pub super_admin: Signer<'info>,
+    pub user: Signer<'info>,
 
-    /// Platform configuration - verify super_admin authority
     #[account(
-        seeds = [SEED_PLATFORM_CONFIG],
-        bump = platform_config.bump,
-        constraint = platform_config.super_admin == super_admin.key() @ PlatformError::Unauthorized,
-        constraint = platform_config.is_initialized @ PlatformError::NotInitialized
+        mut,
+        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), staking_pool.seed_mint().as_ref(), &[staking_pool.token_type as u8]],
+        bump = staking_pool.bump
     )]
-    pub platform_config: Account<'info, PlatformConfig>,
-
-    /// The developer whose record is being closed
-    /// CHECK: This is just used to derive the PDA, not modified
-    pub developer: UncheckedAccount<'info>,
+    pub staking_pool: Account<'info, StakingPool>,
 
-    /// The onboarding record to close
-    /// Rent will be returned to rent_recipient
     #[account(
         mut,
-        seeds = [SEED_DEVELOPER_ONBOARDING, developer.key().as_ref()],
-        bump = onboarding_record.bump,
-        close = rent_recipient,
-        constraint = onboarding_record.developer == developer.key() @ OnboardingError::InvalidDeveloper
+        seeds = [SEED_USER_STAKING, staking_pool.key().as_ref(), user.key().as_ref(), position_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = user_staking_account.owner == user.key() @ StakingError::Unauthorized
     )]
-    pub onboarding_record: Account<'info, DeveloperOnboardingRecord>,
-
-    /// Account to receive the rent from closed account
-    /// Can be super_admin or the developer
-    /// CHECK: Any account can receive rent
-    #[account(mut)]
-    pub rent_recipient: UncheckedAccount<'info>,
+    pub user_staking_account: Account<'info, UserStakingAccount>,
 
     pub system_program: Program<'info, System>,
 }
 
 // ==============================
-// STAKING POOL INITIALIZATION
+// STAKE POSITION MANAGEMENT
 // ==============================
 
 #[derive(Accounts)]
-#[instruction(token_type: TokenType)]
-pub struct InitializeAccounts<'info> {
+#[instruction(position_id: u64)]
+pub struct CloseStakePosition<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
     #[account(
-        init_if_needed,
-        payer = admin,
-        space = StakingPool::LEN,
-        seeds = [SEED_STAKING_POOL, admin.key().as_ref(), &[token_type as u8]],
-        bump
+        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), staking_pool.seed_mint().as_ref(), &[staking_pool.token_type as u8]],
+        bump = staking_pool.bump
     )]
     pub staking_pool: Account<'info, StakingPool>,
 
-    /// CHECK: For SPL, this must be a token account. For SOL, can be any account (we pass SystemProgram)
-    #[account(mut)]
-    pub pool_escrow_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [SEED_USER_STAKE_INDEX, staking_pool.key().as_ref(), user.key().as_ref()],
+        bump = stake_index.bump
+    )]
+    pub stake_index: Account<'info, UserStakeIndex>,
 
-    /// CHECK: For SPL, must be valid mint. For SOL, we pass SystemProgram.programId as dummy
-    pub mint: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = user,
+        seeds = [SEED_USER_STAKING, staking_pool.key().as_ref(), user.key().as_ref(), position_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = user_staking_account.owner == user.key() @ StakingError::Unauthorized,
+        constraint = user_staking_account.staked_amount == 0 @ StakingError::PositionNotEmpty
+    )]
+    pub user_staking_account: Account<'info, UserStakingAccount>,
+}
 
-    #[account(mut)]
-    pub admin: Signer<'info>,
+#[derive(Accounts)]
+pub struct ListStakePositions<'info> {
+    /// CHECK: Owner of the positions being listed; only used for seeds
+    pub owner: UncheckedAccount<'info>,
 
-    pub system_program: Program<'info, System>,
+    #[account(
+        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), staking_pool.seed_mint().as_ref(), &[staking_pool.token_type as u8]],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
 
-    /// CHECK: Token program - only validated when token_type is SPL
-    pub token_program: UncheckedAccount<'info>,
+    #[account(
+        seeds = [SEED_USER_STAKE_INDEX, staking_pool.key().as_ref(), owner.key().as_ref()],
+        bump = stake_index.bump
+    )]
+    pub stake_index: Account<'info, UserStakeIndex>,
 }
 
 // ==============================
-// STAKING OPERATIONS
+// LIQUID STAKING
 // ==============================
 
 #[derive(Accounts)]
-pub struct Stake<'info> {
-    #[account(mut)]
-    pub user: Signer<'info>,
-
+pub struct EnableLiquidStaking<'info> {
     #[account(
         mut,
-        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), &[staking_pool.token_type as u8]],
-        bump = staking_pool.bump
+        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), staking_pool.seed_mint().as_ref(), &[staking_pool.token_type as u8]],
+        bump = staking_pool.bump,
+        constraint = staking_pool.admin == admin.key() @ StakingError::Unauthorized,
+        constraint = !staking_pool.liquid @ StakingError::LiquidPool,
+        constraint = staking_pool.total_staked == 0 @ StakingError::PoolHasStakers,
+        constraint = staking_pool.secondary_rewards.is_empty() @ StakingError::LiquidNotSupported
     )]
     pub staking_pool: Account<'info, StakingPool>,
 
+    // Rewards are compounded into principal, so they must be paid in the staked asset
     #[account(
-        init_if_needed,
-        payer = user,
-        space = UserStakingAccount::LEN,
-        seeds = [SEED_USER_STAKING, staking_pool.key().as_ref(), user.key().as_ref()],
-        bump
+        seeds = [SEED_REWARD_POOL, reward_pool.admin.as_ref(), reward_pool.seed_mint().as_ref(), &[reward_pool.token_type as u8]],
+        bump = reward_pool.bump,
+        constraint = reward_pool.key() == staking_pool.reward_pool @ StakingError::InvalidRewardPool,
+        constraint = reward_pool.token_type == staking_pool.token_type
+            && (staking_pool.token_type == TokenType::SOL || reward_pool.mint == staking_pool.mint)
+            @ StakingError::LiquidNotSupported
     )]
-    pub user_staking_account: Account<'info, UserStakingAccount>,
+    pub reward_pool: Account<'info, RewardPool>,
 
-    /// CHECK: For SPL, this is user's token account. For SOL, dummy (SystemProgram).
-    pub user_token_account: UncheckedAccount<'info>,
+    /// CHECK: Lock tier registry PDA; liquid pools must not have one
+    #[account(
+        seeds = [SEED_LOCK_TIERS, staking_pool.key().as_ref()],
+        bump,
+        constraint = lock_tiers.data_is_empty() @ StakingError::LiquidNotSupported
+    )]
+    pub lock_tiers: UncheckedAccount<'info>,
 
-    /// CHECK: For SPL, this is staking escrow. For SOL, this is the SOL vault (no data).
-    /// Must be writable for both token types.
-    #[account(mut)]
-    pub pool_escrow_account: UncheckedAccount<'info>,
+    /// CHECK: Receipt mint PDA, created in logic
+    #[account(
+        mut,
+        seeds = [SEED_RECEIPT_MINT, staking_pool.key().as_ref()],
+        bump
+    )]
+    pub receipt_mint: UncheckedAccount<'info>,
 
-    /// CHECK: For SPL, must be valid mint. For SOL, dummy (SystemProgram).
+    /// CHECK: For SPL, must be the staking mint. For SOL, dummy (SystemProgram).
     pub mint: UncheckedAccount<'info>,
 
-    /// CHECK: Token program - only used for SPL
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: Token program - must be Token-2022
     pub token_program: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
-// ==============================
-// UNSTAKING OPERATIONS
-// ==============================
-
 #[derive(Accounts)]
-pub struct Unstake<'info> {
+pub struct StakeLiquid<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), &[staking_pool.token_type as u8]],
-        bump = staking_pool.bump
+        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), staking_pool.seed_mint().as_ref(), &[staking_pool.token_type as u8]],
+        bump = staking_pool.bump,
+        constraint = staking_pool.liquid @ StakingError::NotLiquidPool,
+        constraint = !staking_pool.retired @ StakingError::PoolRetired
     )]
     pub staking_pool: Account<'info, StakingPool>,
 
+    #[account(
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_STAKE) @ PlatformError::Paused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_REWARD_POOL, reward_pool.admin.as_ref(), reward_pool.seed_mint().as_ref(), &[reward_pool.token_type as u8]],
+        bump = reward_pool.bump,
+        constraint = reward_pool.key() == staking_pool.reward_pool @ StakingError::InvalidRewardPool
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    /// CHECK: For SPL, the reward escrow. For SOL, dummy (SystemProgram).
+    #[account(mut)]
+    pub reward_escrow_account: UncheckedAccount<'info>,
+
     #[account(
         mut,
-        seeds = [SEED_USER_STAKING, staking_pool.key().as_ref(), user.key().as_ref()],
+        seeds = [SEED_RECEIPT_MINT, staking_pool.key().as_ref()],
         bump,
-        close = user,
-        constraint = user_staking_account.owner == user.key() @ StakingError::Unauthorized
+        address = staking_pool.receipt_mint
     )]
-    pub user_staking_account: Account<'info, UserStakingAccount>,
+    pub receipt_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: User's Token-2022 account for the receipt mint, validated by the token program
+    #[account(mut)]
+    pub user_receipt_account: UncheckedAccount<'info>,
 
     /// CHECK: For SPL, this is user's token account. For SOL, dummy (SystemProgram).
+    #[account(mut)]
     pub user_token_account: UncheckedAccount<'info>,
 
-    /// CHECK: For SPL, this is staking escrow. For SOL, this is the SOL vault (no data).
-    /// Must be writable for both token types.
+    /// CHECK: For SPL, the staking escrow. For SOL, the SOL vault. Validated in logic.
     #[account(mut)]
     pub pool_escrow_account: UncheckedAccount<'info>,
 
-    /// CHECK: For SPL, must be valid mint. For SOL, dummy (SystemProgram).
+    /// CHECK: For SPL, must be the staking mint. For SOL, dummy (SystemProgram).
+    #[account(
+        constraint = staking_pool.token_type == TokenType::SOL
+            || mint.key() == staking_pool.mint @ StakingError::LiquidNotSupported
+    )]
     pub mint: UncheckedAccount<'info>,
 
-    /// CHECK: Token program - only used for SPL
-    pub token_program: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token2022>,
 
     pub system_program: Program<'info, System>,
 }
 
-// ==============================
-// REWARD CLAIMING
-// ==============================
-
 #[derive(Accounts)]
-pub struct ClaimRewards<'info> {
+pub struct UnstakeLiquid<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), &[staking_pool.token_type as u8]],
-        bump = staking_pool.bump
+        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), staking_pool.seed_mint().as_ref(), &[staking_pool.token_type as u8]],
+        bump = staking_pool.bump,
+        constraint = staking_pool.liquid @ StakingError::NotLiquidPool
     )]
     pub staking_pool: Account<'info, StakingPool>,
 
     #[account(
-        mut,
-        seeds = [SEED_USER_STAKING, staking_pool.key().as_ref(), user.key().as_ref()],
-        bump,
-        constraint = user_staking_account.owner == user.key() @ StakingError::Unauthorized
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_UNSTAKE) @ PlatformError::Paused
     )]
-    pub user_staking_account: Account<'info, UserStakingAccount>,
+    pub platform_config: Account<'info, PlatformConfig>,
 
     #[account(
         mut,
-        seeds = [SEED_REWARD_POOL, reward_pool.admin.as_ref(), &[reward_pool.token_type as u8]],
-        bump = reward_pool.bump
+        seeds = [SEED_REWARD_POOL, reward_pool.admin.as_ref(), reward_pool.seed_mint().as_ref(), &[reward_pool.token_type as u8]],
+        bump = reward_pool.bump,
+        constraint = reward_pool.key() == staking_pool.reward_pool @ StakingError::InvalidRewardPool
     )]
     pub reward_pool: Account<'info, RewardPool>,
 
-    /// CHECK: Only used for SPL tokens
+    /// CHECK: For SPL, the reward escrow. For SOL, dummy (SystemProgram).
+    #[account(mut)]
+    pub reward_escrow_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_RECEIPT_MINT, staking_pool.key().as_ref()],
+        bump,
+        address = staking_pool.receipt_mint
+    )]
+    pub receipt_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: User's Token-2022 account for the receipt mint, validated by the token program
+    #[account(mut)]
+    pub user_receipt_account: UncheckedAccount<'info>,
+
+    /// CHECK: For SPL, this is user's token account. For SOL, dummy (SystemProgram).
     #[account(mut)]
     pub user_token_account: UncheckedAccount<'info>,
 
-    /// CHECK: Only used for SPL tokens
+    /// CHECK: For SPL, the staking escrow. For SOL, the SOL vault. Validated in logic.
     #[account(mut)]
-    pub reward_escrow_account: UncheckedAccount<'info>,
+    pub pool_escrow_account: UncheckedAccount<'info>,
 
-    /// CHECK: For SPL, must be valid mint. For SOL, we pass SystemProgram.programId
-    pub mint: UncheckedAccount<'info>, // ✅ Change from InterfaceAccount to UncheckedAccount
+    /// CHECK: For SPL, must be the staking mint. For SOL, dummy (SystemProgram).
+    #[account(
+        constraint = staking_pool.token_type == TokenType::SOL
+            || mint.key() == staking_pool.mint @ StakingError::LiquidNotSupported
+    )]
+    pub mint: UncheckedAccount<'info>,
 
-    /// CHECK: Token program - only used for SPL
-    pub token_program: UncheckedAccount<'info>, // ✅ Change from Program to UncheckedAccount
+    pub token_program: Program<'info, Token2022>,
 
     pub system_program: Program<'info, System>,
 }
 
-// ==============================
-// REWARD ACCRUING
-// ==============================
+#[derive(Accounts)]
+#[instruction(ticket_id: u64)]
+pub struct RequestUnstakeLiquid<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), staking_pool.seed_mint().as_ref(), &[staking_pool.token_type as u8]],
+        bump = staking_pool.bump,
+        constraint = staking_pool.liquid @ StakingError::NotLiquidPool
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_UNSTAKE) @ PlatformError::Paused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
 
-#[derive(Accounts)]
-pub struct AccrueRewards<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_REWARD_POOL, reward_pool.admin.as_ref(), reward_pool.seed_mint().as_ref(), &[reward_pool.token_type as u8]],
+        bump = reward_pool.bump,
+        constraint = reward_pool.key() == staking_pool.reward_pool @ StakingError::InvalidRewardPool
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    /// CHECK: For SPL, the reward escrow. For SOL, dummy (SystemProgram).
     #[account(mut)]
-    pub user: Signer<'info>,
+    pub reward_escrow_account: UncheckedAccount<'info>,
 
+    // Liquid holders have no positions; the index only hands out ticket ids
     #[account(
-        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), &[staking_pool.token_type as u8]],
-        bump = staking_pool.bump
+        init_if_needed,
+        payer = user,
+        space = UserStakeIndex::LEN,
+        seeds = [SEED_USER_STAKE_INDEX, staking_pool.key().as_ref(), user.key().as_ref()],
+        bump
     )]
-    pub staking_pool: Account<'info, StakingPool>,
+    pub stake_index: Account<'info, UserStakeIndex>,
+
+    #[account(
+        init,
+        payer = user,
+        space = UnbondingTicket::LEN,
+        seeds = [SEED_UNBONDING_TICKET, staking_pool.key().as_ref(), user.key().as_ref(), ticket_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub unbonding_ticket: Account<'info, UnbondingTicket>,
 
     #[account(
         mut,
-        seeds = [SEED_USER_STAKING, staking_pool.key().as_ref(), user.key().as_ref()],
+        seeds = [SEED_RECEIPT_MINT, staking_pool.key().as_ref()],
         bump,
-        constraint = user_staking_account.owner == user.key() @ StakingError::Unauthorized
+        address = staking_pool.receipt_mint
     )]
-    pub user_staking_account: Account<'info, UserStakingAccount>,
+    pub receipt_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: User's Token-2022 account for the receipt mint, validated by the token program
+    #[account(mut)]
+    pub user_receipt_account: UncheckedAccount<'info>,
+
+    /// CHECK: For SPL, the staking escrow. For SOL, the SOL vault. Validated in logic.
+    #[account(mut)]
+    pub pool_escrow_account: UncheckedAccount<'info>,
+
+    /// CHECK: For SPL, must be the staking mint. For SOL, dummy (SystemProgram).
+    #[account(
+        constraint = staking_pool.token_type == TokenType::SOL
+            || mint.key() == staking_pool.mint @ StakingError::LiquidNotSupported
+    )]
+    pub mint: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
 
     pub system_program: Program<'info, System>,
 }
@@ -2025,6 +6007,14 @@ pub struct CreateTournamentPool<'info> {
     )]
     pub tournament_pool: Account<'info, TournamentPool>,
 
+    // Platform config - developer share is snapshotted onto the tournament
+    #[account(
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump,
+        constraint = platform_config.is_initialized @ PlatformError::NotInitialized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
     /// CHECK: For SPL, this will be a token account that needs to be created (mutable in CPI).
     /// For SOL, we pass SystemProgram.programId (not mutable).
     /// Mutability is enforced in function logic, not here.
@@ -2059,6 +6049,13 @@ pub struct RegisterForTournament<'info> {
     )]
     pub tournament_pool: Account<'info, TournamentPool>,
 
+    #[account(
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_REGISTRATION) @ PlatformError::Paused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
     #[account(
         init,
         payer = user,
@@ -2091,11 +6088,13 @@ pub struct RegisterForTournament<'info> {
 #[derive(Accounts)]
 #[instruction(token_type: TokenType)]
 pub struct InitializeRewardPool<'info> {
+    pub mint: UncheckedAccount<'info>,
+
     #[account(
         init_if_needed,
         payer = admin,
         space = RewardPool::LEN,
-        seeds = [SEED_REWARD_POOL, admin.key().as_ref(), &[token_type as u8]],
+        seeds = [SEED_REWARD_POOL, admin.key().as_ref(), pool_seed_mint(token_type, &mint.key()).as_ref(), &[token_type as u8]],
         bump
     )]
     pub reward_pool: Account<'info, RewardPool>,
@@ -2105,11 +6104,99 @@ pub struct InitializeRewardPool<'info> {
 
     #[account(mut)]
     pub admin: Signer<'info>,
-    pub mint: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
     pub token_program: UncheckedAccount<'info>,
 }
 
+#[derive(Accounts)]
+pub struct LinkRewardPool<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), staking_pool.seed_mint().as_ref(), &[staking_pool.token_type as u8]],
+        bump = staking_pool.bump,
+        constraint = staking_pool.admin == admin.key() @ StakingError::Unauthorized
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        seeds = [SEED_REWARD_POOL, reward_pool.admin.as_ref(), reward_pool.seed_mint().as_ref(), &[reward_pool.token_type as u8]],
+        bump = reward_pool.bump,
+        constraint = reward_pool.admin == admin.key() @ RewardError::Unauthorized
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        mut,
+        seeds = [SEED_POOL_REGISTRY],
+        bump = pool_registry.bump
+    )]
+    pub pool_registry: Account<'info, PoolRegistry>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AddSecondaryRewardPool<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), staking_pool.seed_mint().as_ref(), &[staking_pool.token_type as u8]],
+        bump = staking_pool.bump,
+        constraint = staking_pool.admin == admin.key() @ StakingError::Unauthorized,
+        constraint = !staking_pool.liquid @ StakingError::LiquidPool
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        seeds = [SEED_REWARD_POOL, reward_pool.admin.as_ref(), reward_pool.seed_mint().as_ref(), &[reward_pool.token_type as u8]],
+        bump = reward_pool.bump,
+        constraint = reward_pool.admin == admin.key() @ RewardError::Unauthorized
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct FundRewardPool<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), staking_pool.seed_mint().as_ref(), &[staking_pool.token_type as u8]],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        mut,
+        seeds = [SEED_REWARD_POOL, reward_pool.admin.as_ref(), reward_pool.seed_mint().as_ref(), &[reward_pool.token_type as u8]],
+        bump = reward_pool.bump,
+        constraint = reward_pool.key() == staking_pool.reward_pool @ StakingError::InvalidRewardPool
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    /// CHECK: For SPL, the reward escrow (validated in logic). For SOL, dummy (SystemProgram).
+    #[account(mut)]
+    pub reward_escrow_account: UncheckedAccount<'info>,
+
+    /// CHECK: For SPL, funder's token account. For SOL, dummy (SystemProgram).
+    #[account(mut)]
+    pub funder_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: For SPL, must be the reward pool mint. For SOL, dummy (SystemProgram).
+    #[account(
+        constraint = reward_pool.token_type == TokenType::SOL
+            || mint.key() == reward_pool.mint @ RewardError::InvalidMint
+    )]
+    pub mint: UncheckedAccount<'info>,
+
+    /// CHECK: Token program - only used for SPL
+    pub token_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
 // ==============================
 // PRIZE POOL INITIALIZATION
 // ==============================
@@ -2153,7 +6240,7 @@ pub struct InitializePrizePool<'info> {
 // DISTRIBUTE TOURNAMENT REVENUE
 // ==============================
 #[derive(Accounts)]
-#[instruction(tournament_id: String, prize_percentage: u8, revenue_percentage: u8, staking_percentage: u8, burn_percentage: u8)]
+#[instruction(tournament_id: String)]
 pub struct DistributeTournamentRevenue<'info> {
     #[account(
         mut,
@@ -2168,10 +6255,11 @@ pub struct DistributeTournamentRevenue<'info> {
     )]
     pub tournament_pool: Account<'info, TournamentPool>,
 
-    // ✅ NEW: Platform config for share percentages
+    // ✅ NEW: Platform config for the platform wallet
     #[account(
         seeds = [SEED_PLATFORM_CONFIG],
-        bump = platform_config.bump
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_DISTRIBUTION) @ PlatformError::Paused
     )]
     pub platform_config: Account<'info, PlatformConfig>,
 
@@ -2184,15 +6272,22 @@ pub struct DistributeTournamentRevenue<'info> {
 
     #[account(
         mut,
-        seeds = [SEED_REWARD_POOL, reward_pool.admin.as_ref(), &[tournament_pool.token_type as u8]],
+        seeds = [SEED_REWARD_POOL, reward_pool.admin.as_ref(), reward_pool.seed_mint().as_ref(), &[reward_pool.token_type as u8]],
         bump = reward_pool.bump
     )]
     pub reward_pool: Account<'info, RewardPool>,
 
     #[account(
         mut,
-        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), &[tournament_pool.token_type as u8]],
-        bump = staking_pool.bump
+        seeds = [SEED_STAKING_POOL, staking_pool.admin.as_ref(), staking_pool.seed_mint().as_ref(), &[staking_pool.token_type as u8]],
+        bump = staking_pool.bump,
+        constraint = staking_pool.pays_from(&reward_pool.key()) @ StakingError::InvalidRewardPool,
+        constraint = platform_config.is_canonical_pair(
+            tournament_pool.token_type,
+            &tournament_pool.mint,
+            &staking_pool.key(),
+            &reward_pool.key()
+        ) @ PlatformError::NonCanonicalPool
     )]
     pub staking_pool: Account<'info, StakingPool>,
 
@@ -2221,6 +6316,7 @@ pub struct DistributeTournamentRevenue<'info> {
 
     pub prize_escrow_account: UncheckedAccount<'info>,
 
+    /// CHECK: For SPL, the reward pool escrow (validated in logic). For SOL, dummy (SystemProgram).
     pub reward_escrow_account: UncheckedAccount<'info>,
 
     pub mint: UncheckedAccount<'info>,
@@ -2247,6 +6343,13 @@ pub struct DistributeTournamentPrizes<'info> {
     )]
     pub tournament_pool: Account<'info, TournamentPool>,
 
+    #[account(
+        seeds = [SEED_PLATFORM_CONFIG],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused(PAUSE_DISTRIBUTION) @ PlatformError::Paused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
     #[account(
         mut,
         seeds = [SEED_PRIZE_POOL, tournament_pool.key().as_ref()],
@@ -2296,6 +6399,7 @@ pub struct DistributeTournamentPrizes<'info> {
 // ==============================
 // Staking Pool
 // ==============================
+/// Seeded by admin, mint and token type; pools from the previous release must be recreated
 #[account]
 pub struct StakingPool {
     pub admin: Pubkey,
@@ -2304,35 +6408,442 @@ pub struct StakingPool {
     pub total_weight: u128,
     pub acc_reward_per_weight: u128,
     pub epoch_index: u64,
+    pub early_unstake_penalty_bps: u16,
+    pub undistributed_rewards: u64, // rewards credited while total_weight was 0
+    pub retired: bool,              // retired pools accept no new stakes
+    pub reward_rate: u128,          // streamed rewards per second, scaled by ACC_PRECISION
+    pub reward_period_end: i64,     // when the current stream runs out
+    pub last_update_time: i64,      // last time the accumulator was advanced
+    pub reward_stream_duration: i64, // seconds each deposit is streamed over; 0 = instant
+    pub emission_rate: u64,         // admin-funded emission, reward units per second
+    pub emission_start: i64,
+    pub emission_end: i64,
+    pub emission_remaining: u64,    // funded emissions not yet credited
+    pub liquid: bool,               // stakes are represented by receipt tokens
+    pub receipt_mint: Pubkey,       // Token-2022 receipt mint (liquid pools only)
+    pub liquid_reward_debt: u128,   // accumulator checkpoint for the liquid pool's weight
+    pub liquid_pending_rewards: u64, // harvested rewards the reward pool could not cover yet
+    pub total_unbonding: u64,       // principal waiting in unbonding tickets
+    pub unbonding_cooldown: i64,    // seconds between request_unstake and withdrawal; 0 = direct unstake
+    pub epoch_duration: i64,        // minimum seconds between advance_epoch calls; 0 = disabled
+    pub epoch_started_at: i64,
+    pub reward_pool: Pubkey,        // reward pool paying this pool's stakers; default until linked
+    pub secondary_rewards: Vec<SecondaryReward>, // up to MAX_SECONDARY_REWARDS extra reward assets
+    pub token_type: TokenType,
+    pub is_initialized: bool,       // set once; initialize_accounts never runs twice on a pool
+    pub bump: u8,
+}
+
+impl StakingPool {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 16 + 16 + 8 + 2 + 8 + 1 + 16 + 8 + 8 + 8 + 8 + 8
+        + 8 + 8 + 1 + 32 + 16 + 8 + 8 + 8 + 8 + 8 + 32
+        + 4 + SecondaryReward::LEN * MAX_SECONDARY_REWARDS
+        + 1 + 1 + 1;
+
+    /// Mint component of this pool's PDA seeds
+    pub fn seed_mint(&self) -> Pubkey {
+        pool_seed_mint(self.token_type, &self.mint)
+    }
+
+    /// True when stakers are paid from `reward_pool`, as primary or secondary rewards
+    pub fn pays_from(&self, reward_pool: &Pubkey) -> bool {
+        *reward_pool == self.reward_pool
+            || self
+                .secondary_rewards
+                .iter()
+                .any(|reward| reward.reward_pool == *reward_pool)
+    }
+
+    /// Credit revenue deposited into `reward_pool` to the accumulator that pays from it
+    pub fn credit_rewards_from(&mut self, reward_pool: &Pubkey, amount: u64, now: i64) -> Result<()> {
+        if *reward_pool == self.reward_pool {
+            return self.credit_rewards(amount, now);
+        }
+
+        let total_weight = self.total_weight;
+        let reward = self
+            .secondary_rewards
+            .iter_mut()
+            .find(|reward| reward.reward_pool == *reward_pool)
+            .ok_or(StakingError::InvalidRewardPool)?;
+        reward.undistributed_rewards = reward
+            .undistributed_rewards
+            .checked_add(amount)
+            .ok_or(StakingError::MathOverflow)?;
+        reward.fold(total_weight);
+        Ok(())
+    }
+
+    /// Advance the accumulator by the rewards streamed and emitted since `last_update_time`
+    /// Emissions while nobody is staked are kept in `undistributed_rewards`
+    pub fn update_pool(&mut self, now: i64) -> Result<()> {
+        let mut emitted: u128 = 0;
+
+        let stream_end = now.min(self.reward_period_end);
+        if self.reward_rate > 0 && stream_end > self.last_update_time {
+            let elapsed = (stream_end - self.last_update_time) as u128;
+            emitted = self
+                .reward_rate
+                .checked_mul(elapsed)
+                .ok_or(StakingError::MathOverflow)?;
+        }
+
+        // Admin-funded schedule, capped by what is left so it stops cleanly
+        if self.emission_remaining > 0 {
+            let from = self.last_update_time.max(self.emission_start);
+            let to = now.min(self.emission_end);
+            if to > from {
+                let scheduled = self.emission_rate.saturating_mul((to - from) as u64);
+                let amount = scheduled.min(self.emission_remaining);
+                self.emission_remaining -= amount;
+                emitted = emitted
+                    .checked_add((amount as u128).saturating_mul(ACC_PRECISION))
+                    .ok_or(StakingError::MathOverflow)?;
+            }
+        }
+
+        if emitted > 0 {
+            if self.total_weight > 0 {
+                self.acc_reward_per_weight = self
+                    .acc_reward_per_weight
+                    .saturating_add(emitted / self.total_weight);
+            } else {
+                let idle = (emitted / ACC_PRECISION).min(u128::from(u64::MAX)) as u64;
+                self.undistributed_rewards = self.undistributed_rewards.saturating_add(idle);
+            }
+        }
+        if now > self.last_update_time {
+            self.last_update_time = now;
+        }
+        Ok(())
+    }
+
+    /// Credit rewards to current stakers, or hold them until there is weight to credit
+    pub fn credit_rewards(&mut self, amount: u64, now: i64) -> Result<()> {
+        self.update_pool(now)?;
+        self.undistributed_rewards = self
+            .undistributed_rewards
+            .checked_add(amount)
+            .ok_or(StakingError::MathOverflow)?;
+        self.fold_undistributed_rewards(now)
+    }
+
+    /// Fold any carried-over rewards into the accumulator once weight is non-zero
+    /// With streaming enabled they restart the stream instead of landing at once
+    pub fn fold_undistributed_rewards(&mut self, now: i64) -> Result<()> {
+        if self.total_weight == 0 {
+            return Ok(());
+        }
+
+        // Secondary rewards are never streamed
+        let total_weight = self.total_weight;
+        for reward in self.secondary_rewards.iter_mut() {
+            reward.fold(total_weight);
+        }
+
+        if self.undistributed_rewards == 0 {
+            return Ok(());
+        }
+
+        let amount_scaled = (self.undistributed_rewards as u128).saturating_mul(ACC_PRECISION);
+        if self.reward_stream_duration == 0 {
+            self.acc_reward_per_weight = self
+                .acc_reward_per_weight
+                .saturating_add(amount_scaled / self.total_weight);
+        } else {
+            // Roll what is left of the current stream into the new one
+            let remaining_scaled = if now < self.reward_period_end {
+                self.reward_rate
+                    .saturating_mul((self.reward_period_end - now) as u128)
+            } else {
+                0
+            };
+            self.reward_rate = remaining_scaled
+                .checked_add(amount_scaled)
+                .ok_or(StakingError::MathOverflow)?
+                / self.reward_stream_duration as u128;
+            self.reward_period_end = now
+                .checked_add(self.reward_stream_duration)
+                .ok_or(StakingError::MathOverflow)?;
+            self.last_update_time = now;
+        }
+        self.undistributed_rewards = 0;
+        Ok(())
+    }
+}
+
+/// Extra reward asset paid to a staking pool's stakers from another reward pool
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SecondaryReward {
+    pub reward_pool: Pubkey,
     pub token_type: TokenType,
+    pub mint: Pubkey,
+    pub acc_reward_per_weight: u128,
+    pub undistributed_rewards: u64, // credited while total_weight was 0
+}
+
+impl SecondaryReward {
+    pub const LEN: usize = 32 + 1 + 32 + 16 + 8;
+
+    /// Fold carried-over rewards into the accumulator once there is weight to credit
+    pub fn fold(&mut self, total_weight: u128) {
+        if total_weight == 0 || self.undistributed_rewards == 0 {
+            return;
+        }
+        let amount_scaled = (self.undistributed_rewards as u128).saturating_mul(ACC_PRECISION);
+        self.acc_reward_per_weight = self
+            .acc_reward_per_weight
+            .saturating_add(amount_scaled / total_weight);
+        self.undistributed_rewards = 0;
+    }
+}
+
+// ==============================
+// User Staking Account
+// ==============================
+/// One account per stake position; single-position accounts from the previous release are not readable
+#[account]
+pub struct UserStakingAccount {
+    pub owner: Pubkey,
+    pub funder: Pubkey,             // wallet that paid for the stake (owner unless delegated)
+    pub position_id: u64,
+    pub staked_amount: u64,
+    pub stake_timestamp: i64,
+    pub lock_duration: i64,
+    pub weight: u128,
+    pub reward_debt: u128,
+    pub pending_rewards: u64,
+    pub lock_tier: u8,
+    pub multiplier_bps: u64,
+    pub weight_checkpoints: Vec<WeightCheckpoint>, // up to MAX_WEIGHT_CHECKPOINTS, oldest first
+    pub secondary_rewards: Vec<SecondaryRewardPosition>, // parallel to the pool's secondary_rewards
+}
+
+impl UserStakingAccount {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 8 + 8 + 16 + 16 + 8 + 1 + 8
+        + 4 + WeightCheckpoint::LEN * MAX_WEIGHT_CHECKPOINTS
+        + 4 + SecondaryRewardPosition::LEN * MAX_SECONDARY_REWARDS;
+
+    /// Move secondary rewards earned at the current weight into their pending amounts
+    /// Entries for accumulators added after the last update start from a zero debt,
+    /// which is exact because accumulators start at zero and the weight has not changed since
+    pub fn settle_secondary_rewards(&mut self, staking_pool: &StakingPool) {
+        let weight = self.weight;
+        self.secondary_rewards
+            .resize(staking_pool.secondary_rewards.len(), SecondaryRewardPosition::default());
+        for (position, reward) in self
+            .secondary_rewards
+            .iter_mut()
+            .zip(staking_pool.secondary_rewards.iter())
+        {
+            let accumulated = weight
+                .saturating_mul(reward.acc_reward_per_weight)
+                .checked_div(ACC_PRECISION)
+                .unwrap_or(0);
+            let pending_now = accumulated.saturating_sub(position.reward_debt);
+            position.pending_rewards = position
+                .pending_rewards
+                .saturating_add(pending_now.min(u128::from(u64::MAX)) as u64);
+            position.reward_debt = accumulated;
+        }
+    }
+
+    /// Checkpoint every secondary accumulator at the current weight
+    pub fn sync_secondary_debts(&mut self, staking_pool: &StakingPool) {
+        let weight = self.weight;
+        self.secondary_rewards
+            .resize(staking_pool.secondary_rewards.len(), SecondaryRewardPosition::default());
+        for (position, reward) in self
+            .secondary_rewards
+            .iter_mut()
+            .zip(staking_pool.secondary_rewards.iter())
+        {
+            position.reward_debt = weight
+                .saturating_mul(reward.acc_reward_per_weight)
+                .checked_div(ACC_PRECISION)
+                .unwrap_or(0);
+        }
+    }
+
+    pub fn has_pending_secondary_rewards(&self) -> bool {
+        self.secondary_rewards
+            .iter()
+            .any(|position| position.pending_rewards > 0)
+    }
+
+    /// Record the current weight as the weight in effect for `epoch`'s snapshot
+    pub fn record_weight_checkpoint(&mut self, epoch: u64) {
+        let weight = self.weight;
+        match self.weight_checkpoints.last_mut() {
+            Some(last) if last.epoch == epoch => last.weight = weight,
+            _ => {
+                if self.weight_checkpoints.len() >= MAX_WEIGHT_CHECKPOINTS {
+                    self.weight_checkpoints.remove(0);
+                }
+                self.weight_checkpoints.push(WeightCheckpoint { epoch, weight });
+            }
+        }
+    }
+
+    /// Weight captured by the snapshot of `epoch`, if still in the checkpoint history
+    pub fn weight_at_epoch(&self, epoch: u64) -> Option<u128> {
+        self.weight_checkpoints
+            .iter()
+            .rev()
+            .find(|checkpoint| checkpoint.epoch <= epoch)
+            .map(|checkpoint| checkpoint.weight)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct SecondaryRewardPosition {
+    pub reward_debt: u128,
+    pub pending_rewards: u64,
+}
+
+impl SecondaryRewardPosition {
+    pub const LEN: usize = 16 + 8;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct WeightCheckpoint {
+    pub epoch: u64,             // first epoch snapshot this weight applies to
+    pub weight: u128,
+}
+
+impl WeightCheckpoint {
+    pub const LEN: usize = 8 + 16;
+}
+
+// ==============================
+// Epoch Snapshot
+// ==============================
+#[account]
+pub struct EpochSnapshot {
+    pub staking_pool: Pubkey,
+    pub epoch: u64,
+    pub total_weight: u128,
+    pub total_staked: u64,
+    pub acc_reward_per_weight: u128,
+    pub recorded_at: i64,
+    pub bump: u8,
+}
+
+impl EpochSnapshot {
+    pub const LEN: usize = 8 + 32 + 8 + 16 + 8 + 16 + 8 + 1;
+}
+
+// ==============================
+// User Stake Index
+// ==============================
+#[account]
+pub struct UserStakeIndex {
+    pub owner: Pubkey,
+    pub staking_pool: Pubkey,
+    pub next_position_id: u64,     // id assigned to the next opened position
+    pub open_positions: Vec<u64>,  // up to MAX_STAKE_POSITIONS
+    pub next_ticket_id: u64,       // id assigned to the next unbonding ticket
+    pub min_delegated_stake: u64,  // smallest stake_for amount accepted; 0 = not accepted
+    pub bump: u8,
+}
+
+impl UserStakeIndex {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 4 + 8 * MAX_STAKE_POSITIONS + 8 + 8 + 1;
+
+    /// Set up a freshly created index; existing indexes are left untouched
+    pub fn init_if_new(&mut self, owner: Pubkey, staking_pool: Pubkey, bump: u8) {
+        if self.owner != Pubkey::default() {
+            return;
+        }
+        self.owner = owner;
+        self.staking_pool = staking_pool;
+        self.next_position_id = 0;
+        self.open_positions = Vec::new();
+        self.next_ticket_id = 0;
+        self.min_delegated_stake = 0;
+        self.bump = bump;
+    }
+
+    pub fn open(&mut self, position_id: u64) -> Result<()> {
+        require!(
+            position_id == self.next_position_id,
+            StakingError::InvalidPositionId
+        );
+        require!(
+            self.open_positions.len() < MAX_STAKE_POSITIONS,
+            StakingError::TooManyPositions
+        );
+        self.open_positions.push(position_id);
+        self.next_position_id = self
+            .next_position_id
+            .checked_add(1)
+            .ok_or(StakingError::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn remove(&mut self, position_id: u64) {
+        self.open_positions.retain(|id| *id != position_id);
+    }
+}
+
+// ==============================
+// Unbonding Ticket
+// ==============================
+#[account]
+pub struct UnbondingTicket {
+    pub owner: Pubkey,
+    pub staking_pool: Pubkey,
+    pub ticket_id: u64,
+    pub amount: u64,
+    pub request_time: i64,
+    pub unlock_time: i64,          // withdraw_unbonded allowed from here
     pub bump: u8,
 }
 
-impl StakingPool {
-    pub const LEN: usize = 8 + 32 + 32 + 8 + 16 + 16 + 8 + 1 + 1;
+impl UnbondingTicket {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 8 + 8 + 1;
 }
 
 // ==============================
-// User Staking Account
+// Lock Tier Registry
 // ==============================
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct LockTier {
+    pub lock_duration: i64,     // seconds
+    pub multiplier_bps: u64,    // weight multiplier, 10_000 = 1.0x
+    pub enabled: bool,          // disabled tiers reject new stakes
+}
+
+impl LockTier {
+    pub const LEN: usize = 8 + 8 + 1;
+}
+
 #[account]
-pub struct UserStakingAccount {
-    pub owner: Pubkey,
-    pub staked_amount: u64,
-    pub stake_timestamp: i64,
-    pub lock_duration: i64,
-    pub weight: u128,
-    pub reward_debt: u128,
-    pub pending_rewards: u64,
+pub struct LockTierRegistry {
+    pub staking_pool: Pubkey,
+    pub tiers: Vec<LockTier>,   // up to MAX_LOCK_TIERS, indexed by tier_index
+    pub bump: u8,
 }
 
-impl UserStakingAccount {
-    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 16 + 16 + 8;
+impl LockTierRegistry {
+    pub const LEN: usize = 8 + 32 + 4 + LockTier::LEN * MAX_LOCK_TIERS + 1;
+
+    pub fn get_enabled(&self, tier_index: u8) -> Result<LockTier> {
+        let tier = self
+            .tiers
+            .get(tier_index as usize)
+            .copied()
+            .ok_or(StakingError::InvalidLockTier)?;
+        require!(tier.enabled, StakingError::LockTierDisabled);
+        Ok(tier)
+    }
 }
 
 // ==============================
 // Tournament Pool
 // ==============================
+/// Stores the revenue split and the developer share in effect at creation;
+/// earlier tournament accounts do not deserialize
 #[account]
 pub struct TournamentPool {
     pub admin: Pubkey,
@@ -2345,11 +6856,16 @@ pub struct TournamentPool {
     pub end_time: i64,
     pub is_active: bool,
     pub token_type: TokenType,
+    pub prize_percentage: u8,
+    pub revenue_percentage: u8,
+    pub staking_percentage: u8,
+    pub burn_percentage: u8,
+    pub developer_share_bps: u16,
     pub bump: u8,
 }
 
 impl TournamentPool {
-    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 8 + 2 + 2 + 8 + 1 + 1 + 1;
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 8 + 2 + 2 + 8 + 1 + 1 + 1 + 1 + 1 + 1 + 2 + 1;
 }
 
 // ==============================
@@ -2402,11 +6918,62 @@ pub struct RewardPool {
 
 impl RewardPool {
     pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 1 + 1;
+
+    /// Mint component of this pool's PDA seeds
+    pub fn seed_mint(&self) -> Pubkey {
+        pool_seed_mint(self.token_type, &self.mint)
+    }
+}
+
+// ==============================
+// Pool Registry
+// ==============================
+#[account]
+pub struct PoolRegistry {
+    pub pools: Vec<RegisteredPool>,
+    pub bump: u8,
+}
+
+impl PoolRegistry {
+    pub const LEN: usize = 8 + 4 + RegisteredPool::LEN * MAX_REGISTERED_POOLS + 1;
+
+    /// Add a staking pool; re-initialized pools keep their existing entry
+    pub fn register(&mut self, entry: RegisteredPool) -> Result<()> {
+        if self.entry_mut(&entry.staking_pool).is_some() {
+            return Ok(());
+        }
+        require!(
+            self.pools.len() < MAX_REGISTERED_POOLS,
+            StakingError::TooManyRegisteredPools
+        );
+        self.pools.push(entry);
+        Ok(())
+    }
+
+    pub fn entry_mut(&mut self, staking_pool: &Pubkey) -> Option<&mut RegisteredPool> {
+        self.pools
+            .iter_mut()
+            .find(|entry| entry.staking_pool == *staking_pool)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RegisteredPool {
+    pub staking_pool: Pubkey,
+    pub token_type: TokenType,
+    pub mint: Pubkey,        // Pubkey::default() for SOL
+    pub reward_pool: Pubkey, // default until link_reward_pool
+}
+
+impl RegisteredPool {
+    pub const LEN: usize = 32 + 1 + 32 + 32;
 }
 
 // ==============================
 // Platform Config
 // ==============================
+/// LEN grew and fields sit ahead of `bump`, so the previous config cannot be reused;
+/// initialize a new one on a fresh deployment (no migrate/realloc path is provided)
 #[account]
 pub struct PlatformConfig {
     pub super_admin: Pubkey,              // 32 bytes
@@ -2415,13 +6982,130 @@ pub struct PlatformConfig {
     pub platform_share_bps: u16,          // 2 bytes
     pub developer_onboarding_fee: u64,    // 8 bytes - NEW (in lamports)
     pub onboarding_fee_enabled: bool,     // 1 byte - NEW (toggle on/off)
+    pub pending_super_admin: Pubkey,      // 32 bytes - default when no handover pending
+    pub pending_platform_wallet: Pubkey,  // 32 bytes - default when no change pending
+    pub admin_council: Pubkey,            // 32 bytes - default until a council is set up
+    pub config_change_delay: i64,         // 8 bytes - timelock for share changes (seconds)
+    pub distribution_bounds: DistributionBounds, // 8 bytes - allowed tournament split ranges
+    pub paused_operations: u16,           // 2 bytes - PAUSE_* bitmask
+    pub queued_developer_share_bps: u16,  // 2 bytes
+    pub queued_platform_share_bps: u16,   // 2 bytes
+    pub queued_config_change_delay: i64,  // 8 bytes
+    pub queued_config_eta: i64,           // 8 bytes - 0 when nothing is queued
+    pub governance_staking_pool: Pubkey,  // 32 bytes - default until governance is configured
+    pub governance_quorum_bps: u16,       // 2 bytes - share of snapshot weight that must vote
+    pub governance_voting_period: i64,    // 8 bytes - seconds
+    pub governance_proposal_count: u64,   // 8 bytes - next governance proposal id
+    pub pool_bindings: Vec<PoolBinding>,  // 4 + 97 * MAX_POOL_BINDINGS bytes - canonical pools per asset
     pub is_initialized: bool,             // 1 byte
     pub bump: u8,                         // 1 byte
 }
 
 impl PlatformConfig {
-    // Updated LEN: 8 (discriminator) + 32 + 32 + 2 + 2 + 8 + 1 + 1 + 1 = 87 bytes
-    pub const LEN: usize = 8 + 32 + 32 + 2 + 2 + 8 + 1 + 1 + 1;
+    // Updated LEN: 8 (discriminator) + 32 + 32 + 2 + 2 + 8 + 1 + 32 + 32 + 32 + 8 + 8 + 2 + 2 + 2 + 8 + 8 + 32 + 2 + 8 + 8 + (4 + 97 * 8) + 1 + 1 = 1051 bytes
+    pub const LEN: usize = 8 + 32 + 32 + 2 + 2 + 8 + 1 + 32 + 32 + 32 + 8
+        + DistributionBounds::LEN
+        + 2
+        + 2 + 2 + 8 + 8
+        + 32 + 2 + 8 + 8
+        + 4 + PoolBinding::LEN * MAX_POOL_BINDINGS
+        + 1 + 1;
+
+    pub fn is_paused(&self, flag: u16) -> bool {
+        self.paused_operations & flag != 0
+    }
+
+    /// Super admin holds every role; other keys need a matching RoleAccount
+    pub fn has_role(&self, authority: &Pubkey, role_account: Option<&RoleAccount>, role: u8) -> bool {
+        *authority == self.super_admin
+            || role_account
+                .map_or(false, |account| account.holder == *authority && account.roles & role != 0)
+    }
+
+    /// Canonical pools for an asset; SOL bindings are keyed by Pubkey::default()
+    pub fn pool_binding(&self, token_type: TokenType, mint: &Pubkey) -> Option<&PoolBinding> {
+        let mint = pool_seed_mint(token_type, mint);
+        self.pool_bindings
+            .iter()
+            .find(|binding| binding.token_type == token_type && binding.mint == mint)
+    }
+
+    /// True when the staking/reward pair is the canonical destination for the asset
+    pub fn is_canonical_pair(
+        &self,
+        token_type: TokenType,
+        mint: &Pubkey,
+        staking_pool: &Pubkey,
+        reward_pool: &Pubkey,
+    ) -> bool {
+        self.pool_binding(token_type, mint).map_or(false, |binding| {
+            binding.staking_pool == *staking_pool && binding.reward_pool == *reward_pool
+        })
+    }
+}
+
+/// Staking and reward pool that receive the staking share of revenue for one asset
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PoolBinding {
+    pub token_type: TokenType,
+    pub mint: Pubkey, // Pubkey::default() for SOL
+    pub staking_pool: Pubkey,
+    pub reward_pool: Pubkey,
+}
+
+impl PoolBinding {
+    pub const LEN: usize = 1 + 32 + 32 + 32;
+}
+
+/// Linear reward emission funded up front by the staking pool admin
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct EmissionSchedule {
+    pub rate: u64,  // reward units per second
+    pub start: i64,
+    pub end: i64,
+}
+
+/// Inclusive min/max percentage for each tournament distribution bucket
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DistributionBounds {
+    pub min_prize_percentage: u8,
+    pub max_prize_percentage: u8,
+    pub min_revenue_percentage: u8,
+    pub max_revenue_percentage: u8,
+    pub min_staking_percentage: u8,
+    pub max_staking_percentage: u8,
+    pub min_burn_percentage: u8,
+    pub max_burn_percentage: u8,
+}
+
+impl DistributionBounds {
+    pub const LEN: usize = 8;
+
+    pub const UNRESTRICTED: DistributionBounds = DistributionBounds {
+        min_prize_percentage: 0,
+        max_prize_percentage: 100,
+        min_revenue_percentage: 0,
+        max_revenue_percentage: 100,
+        min_staking_percentage: 0,
+        max_staking_percentage: 100,
+        min_burn_percentage: 0,
+        max_burn_percentage: 100,
+    };
+
+    /// Each range must be ordered and within 0-100, and some split summing to 100 must fit
+    pub fn is_valid(&self) -> bool {
+        let ranges = [
+            (self.min_prize_percentage, self.max_prize_percentage),
+            (self.min_revenue_percentage, self.max_revenue_percentage),
+            (self.min_staking_percentage, self.max_staking_percentage),
+            (self.min_burn_percentage, self.max_burn_percentage),
+        ];
+        let ordered = ranges.iter().all(|(min, max)| min <= max && *max <= 100);
+        let min_total: u16 = ranges.iter().map(|(min, _)| *min as u16).sum();
+        let max_total: u16 = ranges.iter().map(|(_, max)| *max as u16).sum();
+
+        ordered && min_total <= 100 && max_total >= 100
+    }
 }
 
 // ==============================
@@ -2439,6 +7123,228 @@ impl DeveloperOnboardingRecord {
     pub const LEN: usize = 8 + 32 + 8 + 8 + 1; // 57 bytes
 }
 
+// ==============================
+// Role Account
+// ==============================
+#[account]
+pub struct RoleAccount {
+    pub holder: Pubkey,         // 32 bytes - key the roles belong to
+    pub roles: u8,              // 1 byte - ROLE_* bitset
+    pub granted_by: Pubkey,     // 32 bytes - super admin that last changed the roles
+    pub updated_at: i64,        // 8 bytes
+    pub bump: u8,               // 1 byte
+}
+
+impl RoleAccount {
+    pub const LEN: usize = 8 + 32 + 1 + 32 + 8 + 1; // 82 bytes
+}
+
+// ==============================
+// Admin Council
+// ==============================
+#[account]
+pub struct AdminCouncil {
+    pub members: Vec<Pubkey>,   // up to MAX_COUNCIL_MEMBERS
+    pub threshold: u8,          // approvals required to execute
+    pub proposal_count: u64,    // next proposal id
+    pub membership_version: u64, // bumped on every member or threshold change
+    pub bump: u8,
+}
+
+impl AdminCouncil {
+    pub const LEN: usize = 8 + 4 + 32 * MAX_COUNCIL_MEMBERS + 1 + 8 + 8 + 1;
+
+    pub fn member_index(&self, key: &Pubkey) -> Option<usize> {
+        self.members.iter().position(|member| member == key)
+    }
+
+    /// Check a member or threshold change against the current council
+    pub fn validate_membership_action(&self, action: &CouncilAction) -> Result<()> {
+        match *action {
+            CouncilAction::AddCouncilMember { member } => {
+                require!(
+                    member != Pubkey::default()
+                        && self.member_index(&member).is_none()
+                        && self.members.len() < MAX_COUNCIL_MEMBERS,
+                    CouncilError::InvalidMembers
+                );
+            }
+            CouncilAction::RemoveCouncilMember { member } => {
+                require!(
+                    self.member_index(&member).is_some(),
+                    CouncilError::NotCouncilMember
+                );
+                // The remaining members must still be able to reach the threshold
+                require!(
+                    (self.threshold as usize) < self.members.len(),
+                    CouncilError::InvalidThreshold
+                );
+            }
+            CouncilAction::UpdateCouncilThreshold { threshold } => {
+                require!(
+                    threshold > 0 && threshold as usize <= self.members.len(),
+                    CouncilError::InvalidThreshold
+                );
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Apply a member or threshold change; proposals created before it are voided
+    pub fn apply_membership_action(&mut self, action: &CouncilAction) -> Result<()> {
+        self.validate_membership_action(action)?;
+
+        match *action {
+            CouncilAction::AddCouncilMember { member } => {
+                self.members.push(member);
+                msg!("✅ Council member added: {}", member);
+            }
+            CouncilAction::RemoveCouncilMember { member } => {
+                self.members.retain(|existing| *existing != member);
+                msg!("✅ Council member removed: {}", member);
+            }
+            CouncilAction::UpdateCouncilThreshold { threshold } => {
+                self.threshold = threshold;
+                msg!("✅ Council threshold updated to {}", threshold);
+            }
+            _ => return Ok(()),
+        }
+
+        self.membership_version = self
+            .membership_version
+            .checked_add(1)
+            .ok_or(CouncilError::MathOverflow)?;
+
+        Ok(())
+    }
+}
+
+// ==============================
+// Council Proposal
+// ==============================
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CouncilAction {
+    UpdatePlatformConfig {
+        developer_share_bps: u16,
+        platform_share_bps: u16,
+    },
+    UpdatePlatformWallet {
+        new_platform_wallet: Pubkey,
+    },
+    UpdateDeveloperOnboardingFee {
+        developer_onboarding_fee: u64,
+        onboarding_fee_enabled: bool,
+    },
+    CancelPlatformWalletProposal,
+    UpdateConfigChangeDelay {
+        config_change_delay: i64,
+    },
+    CancelQueuedConfigChange,
+    AddCouncilMember {
+        member: Pubkey,
+    },
+    RemoveCouncilMember {
+        member: Pubkey,
+    },
+    UpdateCouncilThreshold {
+        threshold: u8,
+    },
+}
+
+impl CouncilAction {
+    // 1 (variant) + 32 (largest variant: UpdatePlatformWallet)
+    pub const LEN: usize = 1 + 32;
+}
+
+#[account]
+pub struct CouncilProposal {
+    pub council: Pubkey,
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub action: CouncilAction,
+    pub approvals: u16,         // bitmask indexed by council member position
+    pub approval_count: u8,
+    pub executed: bool,
+    pub created_at: i64,
+    pub membership_version: u64, // council membership the approvals were collected under
+    pub bump: u8,
+}
+
+impl CouncilProposal {
+    pub const LEN: usize = 8 + 32 + 8 + 32 + CouncilAction::LEN + 2 + 1 + 1 + 8 + 8 + 1;
+
+    /// Reject proposals that have expired or predate a membership change
+    pub fn require_live(&self, council: &AdminCouncil, now: i64) -> Result<()> {
+        require!(
+            self.membership_version == council.membership_version,
+            CouncilError::ProposalStale
+        );
+        let expires_at = self
+            .created_at
+            .checked_add(COUNCIL_PROPOSAL_EXPIRY)
+            .ok_or(CouncilError::MathOverflow)?;
+        require!(now < expires_at, CouncilError::ProposalExpired);
+        Ok(())
+    }
+}
+
+// ==============================
+// Governance Proposal
+// ==============================
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GovernanceAction {
+    UpdateDistributionBounds {
+        distribution_bounds: DistributionBounds,
+    },
+    // Platform share becomes 10_000 - developer_share_bps
+    UpdateDeveloperShare {
+        developer_share_bps: u16,
+    },
+}
+
+impl GovernanceAction {
+    // 1 (variant) + 8 (largest variant: UpdateDistributionBounds)
+    pub const LEN: usize = 1 + DistributionBounds::LEN;
+}
+
+#[account]
+pub struct GovernanceProposal {
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub staking_pool: Pubkey,       // pool whose weight votes
+    pub action: GovernanceAction,
+    pub created_at: i64,
+    pub voting_ends_at: i64,
+    pub snapshot_epoch: u64,        // epoch snapshot whose weights vote
+    pub total_weight_snapshot: u128, // snapshot total weight, base for quorum
+    pub quorum_bps: u16,
+    pub votes_for: u128,
+    pub votes_against: u128,
+    pub executed: bool,
+    pub cancelled: bool,            // withdrawn by the proposer; can no longer pass
+    pub bump: u8,
+}
+
+impl GovernanceProposal {
+    pub const LEN: usize =
+        8 + 8 + 32 + 32 + GovernanceAction::LEN + 8 + 8 + 8 + 16 + 2 + 16 + 16 + 1 + 1 + 1;
+}
+
+#[account]
+pub struct VoteRecord {
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub position_id: u64,
+    pub weight: u128,
+    pub support: bool,
+    pub bump: u8,
+}
+
+impl VoteRecord {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 16 + 1 + 1;
+}
+
 // ==============================
 // ERROR CODES
 // ==============================
@@ -2494,6 +7400,18 @@ pub enum TournamentError {
 
     #[msg("Invalid escrow account provided")]
     InvalidEscrowAccount,
+
+    #[msg("Prize percentage is outside the platform bounds.")]
+    PrizePercentageOutOfBounds,
+
+    #[msg("Revenue percentage is outside the platform bounds.")]
+    RevenuePercentageOutOfBounds,
+
+    #[msg("Staking percentage is outside the platform bounds.")]
+    StakingPercentageOutOfBounds,
+
+    #[msg("Burn percentage is outside the platform bounds.")]
+    BurnPercentageOutOfBounds,
 }
 
 #[error_code]
@@ -2513,7 +7431,7 @@ pub enum StakingError {
     #[msg("Unstaking is locked - lock period not expired")]
     StakeLockActive,
 
-    #[msg("Invalid Lock Duration, Must be 1, 3, 6 or 12 months")]
+    #[msg("Invalid lock duration")]
     InvalidLockDuration,
 
     #[msg("Invalid token program provided")]
@@ -2524,6 +7442,120 @@ pub enum StakingError {
 
     #[msg("Insufficient balance for this operation")]
     InsufficientBalance,
+
+    #[msg("Lock tier does not exist")]
+    InvalidLockTier,
+
+    #[msg("Lock tier is disabled")]
+    LockTierDisabled,
+
+    #[msg("Lock multiplier must be between 1.0x and MAX_LOCK_MULTIPLIER_BPS")]
+    InvalidLockMultiplier,
+
+    #[msg("Lock tier registry is full")]
+    TooManyLockTiers,
+
+    #[msg("Early unstaking is disabled for this pool")]
+    EarlyUnstakeDisabled,
+
+    #[msg("Penalty must not exceed 10000 basis points")]
+    InvalidPenalty,
+
+    #[msg("Position id must be the next id in the user's stake index")]
+    InvalidPositionId,
+
+    #[msg("Maximum number of open stake positions reached")]
+    TooManyPositions,
+
+    #[msg("Position still holds staked tokens")]
+    PositionNotEmpty,
+
+    #[msg("New lock tier must be longer than the current lock")]
+    LockNotExtended,
+
+    #[msg("New lock tier must not lower the position's multiplier")]
+    MultiplierReduced,
+
+    #[msg("Compounding requires an SPL pool whose reward mint is the staking mint")]
+    CompoundNotSupported,
+
+    #[msg("Staking pool is retired")]
+    PoolRetired,
+
+    #[msg("Staking pool is not retired")]
+    PoolNotRetired,
+
+    #[msg("Staking pool still has stakers")]
+    PoolHasStakers,
+
+    #[msg("No undistributed rewards to reclaim")]
+    NoUndistributedRewards,
+
+    #[msg("No rewards available to claim")]
+    NoRewardsToClaim,
+
+    #[msg("Reward stream duration must not be negative")]
+    InvalidStreamDuration,
+
+    #[msg("Liquid staking needs SOL or a reward mint equal to the staking mint, and no lock tiers")]
+    LiquidNotSupported,
+
+    #[msg("Staking pool is in liquid mode")]
+    LiquidPool,
+
+    #[msg("Staking pool is not in liquid mode")]
+    NotLiquidPool,
+
+    #[msg("Invalid receipt share amount")]
+    InvalidShareAmount,
+
+    #[msg("Pool has an unbonding cooldown - use request_unstake")]
+    UnbondingRequired,
+
+    #[msg("Unbonding cooldown must not be negative")]
+    InvalidUnbondingCooldown,
+
+    #[msg("Ticket id must be the next id in the user's stake index")]
+    InvalidTicketId,
+
+    #[msg("Unbonding cooldown has not passed")]
+    UnbondingNotComplete,
+
+    #[msg("Invalid epoch duration - epochs are disabled when it is 0")]
+    InvalidEpochDuration,
+
+    #[msg("Current epoch has not lasted epoch_duration yet")]
+    EpochNotElapsed,
+
+    #[msg("Weight checkpoint for this epoch is not available")]
+    CheckpointUnavailable,
+
+    #[msg("Reward pool is not the one linked to this staking pool")]
+    InvalidRewardPool,
+
+    #[msg("Reward pool can only be changed while the staking pool is empty and owes no rewards")]
+    RewardPoolInUse,
+
+    #[msg("Staking pool has no reward pool linked")]
+    RewardPoolNotLinked,
+
+    #[msg("Pool registry is full")]
+    TooManyRegisteredPools,
+
+    #[msg("Reward pool already pays this staking pool")]
+    SecondaryRewardExists,
+
+    #[msg("Too many secondary reward pools")]
+    TooManySecondaryRewards,
+
+    #[msg("Secondary reward accounts must come in groups of SECONDARY_CLAIM_ACCOUNTS, one per secondary reward")]
+    InvalidSecondaryAccounts,
+
+    #[msg("Beneficiary does not accept stakes from other wallets")]
+    DelegatedStakeNotAccepted,
+
+    #[msg("Stake is below the beneficiary's delegated stake minimum")]
+    DelegatedStakeTooSmall,
 }
 #[error_code]
 pub enum RewardError {
@@ -2541,6 +7573,18 @@ pub enum RewardError {
 
     #[msg("Invalid escrow account provided")]
     InvalidEscrowAccount,
+
+    #[msg("Amount must be greater than zero")]
+    InvalidAmount,
+
+    #[msg("Emission schedule must have a positive rate, start in the future and fund exactly rate * duration")]
+    InvalidEmissionSchedule,
+
+    #[msg("An emission schedule is still running")]
+    EmissionScheduleActive,
+
+    #[msg("Mint does not match the reward pool")]
+    InvalidMint,
 }
 
 #[error_code]
@@ -2554,11 +7598,68 @@ pub enum PlatformError {
     #[msg("Platform not initialized")]
     NotInitialized,
 
-    #[msg("Unauthorized - only super admin can perform this action")]
+    #[msg("Unauthorized - signer lacks the required role")]
     Unauthorized,
 
     #[msg("Invalid platform wallet provided")]
     InvalidPlatformWallet,
+
+    #[msg("Invalid super admin provided")]
+    InvalidPendingSuperAdmin,
+
+    #[msg("No super admin handover is pending")]
+    NoPendingSuperAdmin,
+
+    #[msg("Signer is not the pending super admin")]
+    NotPendingSuperAdmin,
+
+    #[msg("No platform wallet change is pending")]
+    NoPendingPlatformWallet,
+
+    #[msg("Signer is not the pending platform wallet")]
+    NotPendingPlatformWallet,
+
+    #[msg("This setting is governed by the admin council")]
+    CouncilGoverned,
+
+    #[msg("This setting is controlled by stake-weighted governance")]
+    GovernanceControlled,
+
+    #[msg("Config change delay must not be negative")]
+    InvalidConfigChangeDelay,
+
+    #[msg("A config change is already queued")]
+    ConfigChangeAlreadyQueued,
+
+    #[msg("No config change is queued")]
+    NoQueuedConfigChange,
+
+    #[msg("Queued config change is still timelocked")]
+    ConfigChangeNotReady,
+
+    #[msg("Distribution bounds must be ordered, within 0-100 and admit a split summing to 100")]
+    InvalidDistributionBounds,
+
+    #[msg("This operation is currently paused")]
+    Paused,
+
+    #[msg("Unknown pause flags")]
+    InvalidPauseFlags,
+
+    #[msg("Roles must be a non-empty combination of ROLE_* flags")]
+    InvalidRoles,
+
+    #[msg("Math overflow occurred")]
+    MathOverflow,
+
+    #[msg("Staking or reward pool is not the canonical destination for this asset")]
+    NonCanonicalPool,
+
+    #[msg("Staking and reward pools do not match the requested asset")]
+    InvalidPoolBinding,
+
+    #[msg("Too many canonical pool bindings")]
+    TooManyPoolBindings,
 }
 #[error_code]
 pub enum OnboardingError {
@@ -2573,4 +7674,85 @@ pub enum OnboardingError {
 
     #[msg("Invalid developer address for this onboarding record")]
     InvalidDeveloper,
-}
\ No newline at end of file
+}
+
+#[error_code]
+pub enum CouncilError {
+    #[msg("Council members must be unique, non-empty and at most MAX_COUNCIL_MEMBERS")]
+    InvalidMembers,
+
+    #[msg("Threshold must be between 1 and the number of council members")]
+    InvalidThreshold,
+
+    #[msg("Signer is not a council member")]
+    NotCouncilMember,
+
+    #[msg("Council member has already approved this proposal")]
+    AlreadyApproved,
+
+    #[msg("Council proposal has already been executed")]
+    ProposalAlreadyExecuted,
+
+    #[msg("Council proposal has not reached the approval threshold")]
+    ThresholdNotMet,
+
+    #[msg("Admin council does not match platform config")]
+    InvalidCouncil,
+
+    #[msg("Council proposal has expired")]
+    ProposalExpired,
+
+    #[msg("Council membership changed since this proposal was created")]
+    ProposalStale,
+
+    #[msg("Math overflow occurred")]
+    MathOverflow,
+}
+
+#[error_code]
+pub enum GovernanceError {
+    #[msg("Governance has not been configured")]
+    NotConfigured,
+
+    #[msg("Quorum must be between 1 and 10000 basis points")]
+    InvalidQuorum,
+
+    #[msg("Voting period must be positive")]
+    InvalidVotingPeriod,
+
+    #[msg("Staking pool is not the governance pool for this proposal")]
+    InvalidStakingPool,
+
+    #[msg("Position has no voting weight")]
+    NoVotingWeight,
+
+    #[msg("Governance pool has no completed epoch snapshot")]
+    NoEpochSnapshot,
+
+    #[msg("Voting period has ended")]
+    VotingClosed,
+
+    #[msg("Voting period has not ended")]
+    VotingOpen,
+
+    #[msg("Governance proposal has already been executed")]
+    ProposalAlreadyExecuted,
+
+    #[msg("Quorum not reached")]
+    QuorumNotMet,
+
+    #[msg("Proposal did not pass")]
+    ProposalRejected,
+
+    #[msg("Execution window for this proposal has passed")]
+    ProposalExpired,
+
+    #[msg("Governance proposal has been cancelled")]
+    ProposalCancelled,
+
+    #[msg("Only the proposer can cancel this proposal")]
+    NotProposer,
+
+    #[msg("Math overflow occurred")]
+    MathOverflow,
+}
@@ -1,7 +1,7 @@
 import { PublicKey, SystemProgram } from "@solana/web3.js";
 import * as anchor from "@project-serum/anchor";
 import { getProgram } from "../staking/services";
-import { getPlatformConfigPDA, getDeveloperOnboardingRecordPDA, SEEDS } from "../utils/getPDAs";
+import { getPlatformConfigPDA, getDeveloperOnboardingRecordPDA, resolveRoleAccount, SEEDS } from "../utils/getPDAs";
 import { DeveloperOnboardingRecordAccount, PlatformConfigAccount } from "../adminDashboard/services";
 import { createClerkClient } from "@clerk/backend";
 import { db } from "../config/firebase";
@@ -80,9 +80,9 @@ export const getDeveloperOnboardingConfig = async (): Promise<{
 
 /**
  * Builds a transaction to update the developer onboarding fee
- * Only callable by super_admin
+ * Only callable by super_admin or a fee manager
  * 
- * @param adminPublicKey - The super admin's public key
+ * @param adminPublicKey - The super admin's or fee manager's public key
  * @param developerOnboardingFee - Fee amount in lamports
  * @param onboardingFeeEnabled - Whether to enable/disable fees
  */
@@ -119,7 +119,8 @@ export const buildUpdateDeveloperOnboardingFeeTransaction = async (
             )
             .accounts({
                 platformConfig: platformConfigPda,
-                superAdmin: adminPublicKey,
+                roleAccount: await resolveRoleAccount(adminPublicKey),
+                authority: adminPublicKey,
             })
             .transaction();
 
@@ -557,7 +558,8 @@ export const buildFlushDeveloperTransaction = async (
         const transaction = await program.methods
             .closeDeveloperOnboardingRecord()
             .accounts({
-                superAdmin: adminPublicKey,
+                authority: adminPublicKey,
+                roleAccount: await resolveRoleAccount(adminPublicKey),
                 platformConfig: platformConfigPda,
                 developer: developerPublicKey,
                 onboardingRecord: onboardingRecordPda,
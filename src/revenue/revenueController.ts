@@ -4,7 +4,7 @@ import { ref, get, update, set } from "firebase/database";
 import { db } from "../config/firebase";
 import { Request, Response } from 'express';
 import { PublicKey } from '@solana/web3.js';
-import { distributeTournamentRevenueService, distributeTournamentPrizesService, DEFAULT_SPLITS } from './services';
+import { distributeTournamentRevenueService, distributeTournamentPrizesService } from './services';
 import { getProgram } from "../staking/services";
 import { TokenType } from "../utils/getPDAs";
 import { getRevenuePoolStatsService } from "../adminDashboard/dashboardStatsService";
@@ -17,10 +17,6 @@ export const distributeTournamentRevenueController = async (req: Request, res: R
   try {
     const { 
       tournamentId, 
-      prizePercentage, 
-      revenuePercentage, 
-      stakingPercentage,
-      burnPercentage,
       adminPublicKey,
       tokenType
     } = req.body;
@@ -67,70 +63,9 @@ export const distributeTournamentRevenueController = async (req: Request, res: R
       });
     }
 
-    // Validate custom percentages if provided
-    const useCustomPercentages = prizePercentage !== undefined || 
-                               revenuePercentage !== undefined || 
-                               stakingPercentage !== undefined ||
-                               burnPercentage !== undefined;
-     
-    let finalPrizePercentage = DEFAULT_SPLITS.PRIZE_POOL;
-    let finalRevenuePercentage = DEFAULT_SPLITS.REVENUE_POOL;
-    let finalStakingPercentage = DEFAULT_SPLITS.STAKING_REWARD_POOL;
-    let finalBurnPercentage = DEFAULT_SPLITS.BURN;
-
-    if (useCustomPercentages) {
-      // Ensure all percentages are provided if any are provided
-      if (prizePercentage === undefined || 
-          revenuePercentage === undefined || 
-          stakingPercentage === undefined ||
-          burnPercentage === undefined) {
-        return res.status(400).json({
-          success: false,
-          message: "If custom percentages are provided, all percentages (prize, revenue, staking, and burn) must be specified"
-        });
-      }
-      
-      // Validate percentages add up to 100
-      if (prizePercentage + revenuePercentage + stakingPercentage + burnPercentage !== 100) {
-        return res.status(400).json({
-          success: false,
-          message: `Percentages must add up to 100%. Current total: ${prizePercentage + revenuePercentage + stakingPercentage + burnPercentage}%`
-        });
-      }
-      
-      // Validate individual percentages are within valid ranges
-      if (prizePercentage < 0 || prizePercentage > 100 ||
-          revenuePercentage < 0 || revenuePercentage > 100 ||
-          stakingPercentage < 0 || stakingPercentage > 100 ||
-          burnPercentage < 0 || burnPercentage > 100) {
-        return res.status(400).json({
-          success: false,
-          message: "All percentages must be between 0 and 100"
-        });
-      }
-
-      finalPrizePercentage = prizePercentage;
-      finalRevenuePercentage = revenuePercentage;
-      finalStakingPercentage = stakingPercentage;
-      finalBurnPercentage = burnPercentage;
-
-      console.log("✅ Using custom distribution percentages:");
-    } else {
-      console.log("✅ Using default distribution percentages:");
-    }
-
-    console.log(`   Prize: ${finalPrizePercentage}%`);
-    console.log(`   Revenue: ${finalRevenuePercentage}%`);
-    console.log(`   Staking: ${finalStakingPercentage}%`);
-    console.log(`   Burn: ${finalBurnPercentage}%`);
-
-    // Call the service function
+    // Call the service function (the split was fixed when the tournament pool was created)
     const result = await distributeTournamentRevenueService(
       tournamentId,
-      finalPrizePercentage,
-      finalRevenuePercentage,
-      finalStakingPercentage,
-      finalBurnPercentage,
       adminPubKey,
       tt as TokenType
     );
@@ -69,14 +69,10 @@ return { tokenAccount, createInstruction: null };
 
 
 /**
-* Distribute tournament revenue according to the specified percentages
+* Distribute tournament revenue according to the split stored on the tournament pool
 */
 export const distributeTournamentRevenueService = async (
 tournamentId: string,
-prizePercentage: number = DEFAULT_SPLITS.PRIZE_POOL,
-revenuePercentage: number = DEFAULT_SPLITS.REVENUE_POOL,
-stakingPercentage: number = DEFAULT_SPLITS.STAKING_REWARD_POOL,
-burnPercentage: number = DEFAULT_SPLITS.BURN,
 adminPublicKey: PublicKey,
 tokenType: TokenType
 ) => {
@@ -136,12 +132,6 @@ try {
   const prizePoolPublicKey = getPrizePoolPDA(tournamentPoolPublicKey);
   console.log("🔹 Prize Pool PDA:", prizePoolPublicKey.toString());
 
-  const stakingPoolPublicKey = getStakingPoolPDA(platformAdmin, tokenType);
-  console.log("🔹 Staking Pool PDA:", stakingPoolPublicKey.toString());
-
-  const rewardPoolPublicKey = getRewardPoolPDA(platformAdmin, tokenType);
-  console.log("🔹 Reward Pool PDA:", rewardPoolPublicKey.toString());
-
   // 4. Fetch tournament data from blockchain
   console.log("Fetching tournament data from blockchain...");
   const tournamentPoolResult = await getTournamentPool(tournamentId, adminPublicKey, tokenType);
@@ -155,6 +145,20 @@ try {
   
   const tournamentPoolData = tournamentPoolResult.data;
   const totalFunds = Number(tournamentPoolData.totalFunds);
+
+  // Staking and reward pools are seeded by the tournament's mint
+  const poolMint = tokenType === TokenType.SOL ? undefined : new PublicKey(tournamentPoolData.mint);
+  const stakingPoolPublicKey = getStakingPoolPDA(platformAdmin, tokenType, poolMint);
+  console.log("🔹 Staking Pool PDA:", stakingPoolPublicKey.toString());
+
+  const rewardPoolPublicKey = getRewardPoolPDA(platformAdmin, tokenType, poolMint);
+  console.log("🔹 Reward Pool PDA:", rewardPoolPublicKey.toString());
+  const {
+    prizePercentage,
+    revenuePercentage,
+    stakingPercentage,
+    burnPercentage
+  } = tournamentPoolData;
   
   console.log("🔹 Total Tournament Funds:", totalFunds);
   
@@ -242,15 +246,7 @@ try {
     }
   }
 
-  // 7. Validate percentages
-  const totalPercentage = prizePercentage + revenuePercentage + stakingPercentage + burnPercentage;
-  if (totalPercentage !== 100) {
-    return {
-      success: false,
-      message: `Percentages must add up to 100. Current total: ${totalPercentage}%`
-    };
-  }
-
+  // 7. Log the split stored on the tournament pool
   console.log("📊 Distribution Percentages:");
   console.log(`   Prize Pool: ${prizePercentage}%`);
   console.log(`   Revenue Pool: ${revenuePercentage}%`);
@@ -267,13 +263,7 @@ try {
   });
 
   const distributionInstruction = await program.methods
-    .distributeTournamentRevenue(
-      tournamentId,
-      prizePercentage,
-      revenuePercentage,
-      stakingPercentage,
-      burnPercentage
-    )
+    .distributeTournamentRevenue(tournamentId)
     .accounts({
       creator: adminPublicKey,
       tournamentPool: tournamentPoolPublicKey,
@@ -562,6 +552,7 @@ export const distributeTournamentPrizesService = async (
       .accounts({
         creator: adminPublicKey,
         tournamentPool: tournamentPoolPublicKey,
+        platformConfig: getPlatformConfigPDA(),
         prizePool: prizePoolPublicKey,
         prizeEscrowAccount: prizeEscrowPublicKey,
         firstPlaceWinner: firstPlacePublicKey,
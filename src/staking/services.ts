@@ -16,18 +16,30 @@ import {
   TOKEN_2022_PROGRAM_ID,
 } from "@solana/spl-token";
 import dotenv from "dotenv";
-import { getStakingPoolPDA, getStakingEscrowPDA, getUserStakingPDA, getRewardPoolPDA, getRewardEscrowPDA, TokenType, getSOLVaultPDA } from "../utils/getPDAs";
+import { getStakingPoolPDA, getStakingEscrowPDA, getUserStakingPDA, getRewardEscrowPDA, getPlatformConfigPDA, getLockTiersPDA, getUserStakeIndexPDA, TokenType, getSOLVaultPDA } from "../utils/getPDAs";
 dotenv.config();
 
 
 export interface UserStakingAccount {
   owner: PublicKey;
+  positionId: anchor.BN;
   stakedAmount: anchor.BN;
   stakeTimestamp: anchor.BN;
   lockDuration: anchor.BN;
   weight: anchor.BN;
   rewardDebt: anchor.BN;
   pendingRewards: anchor.BN;
+  lockTier: number;
+  multiplierBps: anchor.BN;
+}
+
+export interface UserStakeIndexAccount {
+  owner: PublicKey;
+  stakingPool: PublicKey;
+  nextPositionId: anchor.BN;
+  openPositions: anchor.BN[];
+  minDelegatedStake: anchor.BN;
+  bump: number;
 }
 
 
@@ -72,7 +84,7 @@ export const stakeTokenService = async (
   mintPublicKey: PublicKey,
   userPublicKey: PublicKey,
   amount: number,
-  lockDuration: number,
+  tierIndex: number,
   adminPublicKey: PublicKey,
   tokenType: TokenType
 ) => {
@@ -83,15 +95,21 @@ export const stakeTokenService = async (
     console.log("User PublicKey:", userPublicKey.toBase58());
     console.log("Admin PublicKey:", adminPublicKey.toBase58());
     console.log("Amount to stake:", amount);
-    console.log("Lock Duration (in seconds):", lockDuration);
+    console.log("Lock Tier Index:", tierIndex);
     console.log("Token Type:", tokenType === TokenType.SPL ? "SPL" : "SOL");
 
-    if (!lockDuration || typeof lockDuration !== 'number') {
-      throw new Error('Invalid lock duration provided');
+    if (!Number.isInteger(tierIndex) || tierIndex < 0) {
+      throw new Error('Invalid lock tier index provided');
     }
 
-    const stakingPoolPublicKey = getStakingPoolPDA(adminPublicKey, tokenType);
-    const userStakingAccountPublicKey = getUserStakingPDA(stakingPoolPublicKey, userPublicKey);
+    const stakingPoolPublicKey = getStakingPoolPDA(adminPublicKey, tokenType, mintPublicKey);
+
+    // Each stake opens a new position; its id comes from the user's stake index
+    const stakeIndexPublicKey = getUserStakeIndexPDA(stakingPoolPublicKey, userPublicKey);
+    const stakeIndex = await program.account.userStakeIndex.fetchNullable(stakeIndexPublicKey) as UserStakeIndexAccount | null;
+    const positionId = stakeIndex ? stakeIndex.nextPositionId : new anchor.BN(0);
+    const userStakingAccountPublicKey = getUserStakingPDA(stakingPoolPublicKey, userPublicKey, positionId);
+    console.log("Position ID:", positionId.toString());
 
     // ✅ FIX: Use actual SOL vault PDA
     const poolEscrowAccountPublicKey = tokenType === TokenType.SOL 
@@ -131,10 +149,14 @@ export const stakeTokenService = async (
 
     // Build instruction
     const instruction = await program.methods
-      .stake(new anchor.BN(amountInBaseUnits), new anchor.BN(lockDuration))
+      .stake(positionId, new anchor.BN(amountInBaseUnits), tierIndex)
       .accounts({
         user: userPublicKey,
+        beneficiary: userPublicKey,
         stakingPool: stakingPoolPublicKey,
+        platformConfig: getPlatformConfigPDA(),
+        lockTiers: getLockTiersPDA(stakingPoolPublicKey),
+        stakeIndex: stakeIndexPublicKey,
         userStakingAccount: userStakingAccountPublicKey,
         userTokenAccount: userTokenAccountPublicKey,
         poolEscrowAccount: poolEscrowAccountPublicKey,
@@ -173,6 +195,7 @@ export const stakeTokenService = async (
     return {
       success: true,
       message: "Transaction created successfully!",
+      positionId: positionId.toString(),
       transaction: transaction.serialize({ requireAllSignatures: false }).toString('base64'),
     };
   } catch (err) {
@@ -184,12 +207,15 @@ export const stakeTokenService = async (
   }
 };
 
-// Function to unstake tokens from the staking pool
+// Function to unstake tokens from a stake position
+// Omitting amount withdraws the whole position
 export const unstakeTokenService = async (
   mintPublicKey: PublicKey,
   userPublicKey: PublicKey,
   adminPublicKey: PublicKey,
-  tokenType: TokenType
+  tokenType: TokenType,
+  positionId: number,
+  amount?: number
 ) => {
   try {
     const { program, connection } = getProgram();
@@ -197,25 +223,32 @@ export const unstakeTokenService = async (
     console.log("Unstaking Details:");
     console.log("User PublicKey:", userPublicKey.toBase58());
     console.log("Admin PublicKey:", adminPublicKey.toBase58());
+    console.log("Position ID:", positionId);
     console.log("Token Type:", tokenType === TokenType.SPL ? "SPL" : "SOL");
 
-    const stakingPoolPublicKey = getStakingPoolPDA(adminPublicKey, tokenType);
-    const userStakingAccountPublicKey = getUserStakingPDA(stakingPoolPublicKey, userPublicKey);
+    const stakingPoolPublicKey = getStakingPoolPDA(adminPublicKey, tokenType, mintPublicKey);
+    const stakeIndexPublicKey = getUserStakeIndexPDA(stakingPoolPublicKey, userPublicKey);
+    const userStakingAccountPublicKey = getUserStakingPDA(stakingPoolPublicKey, userPublicKey, positionId);
 
     // ✅ FIX: Use actual SOL vault PDA, not SystemProgram
     const poolEscrowAccountPublicKey = tokenType === TokenType.SOL
       ? getSOLVaultPDA(stakingPoolPublicKey)  // ✅ Use actual SOL vault
       : getStakingEscrowPDA(stakingPoolPublicKey);
 
-    const userStakingAccountResponse = await getUserStakingAccount(userPublicKey, adminPublicKey, tokenType);
+    const userStakingAccountResponse = await getUserStakingAccount(userPublicKey, adminPublicKey, tokenType, positionId, mintPublicKey);
     console.log("User Staking Account Response:", userStakingAccountResponse);
     
-    if (userStakingAccountResponse.success && userStakingAccountResponse.data) {
-      const stakedAmount = userStakingAccountResponse.data.stakedAmount;
-      const stakedAmountRaw = userStakingAccountResponse.data.stakedAmountRaw || '0';
-      console.log(`Unstaking ${stakedAmount} tokens (${stakedAmountRaw} base units)`);
+    if (!userStakingAccountResponse.success || !userStakingAccountResponse.data) {
+      throw new Error(userStakingAccountResponse.message || 'User staking account not found');
     }
 
+    const stakedAmountRaw = userStakingAccountResponse.data.stakedAmountRaw || '0';
+    const tokenDecimals = 9;
+    const amountInBaseUnits = amount === undefined
+      ? new anchor.BN(stakedAmountRaw)
+      : new anchor.BN(Math.floor(amount * Math.pow(10, tokenDecimals)));
+    console.log(`Unstaking ${amountInBaseUnits.toString()} of ${stakedAmountRaw} staked base units`);
+
     const actualMint = tokenType === TokenType.SOL 
       ? SystemProgram.programId
       : mintPublicKey;
@@ -239,17 +272,42 @@ export const unstakeTokenService = async (
       userTokenAccountPublicKey = SystemProgram.programId;
     }
 
+    // Pending rewards are paid out on unstake, so the linked reward pool's accounts are needed too
+    const stakingPoolAccount: any = await program.account.stakingPool.fetch(stakingPoolPublicKey);
+    const rewardPoolPublicKey = stakingPoolAccount.rewardPool as PublicKey;
+    let rewardEscrowPublicKey: PublicKey = SystemProgram.programId;
+    let userRewardTokenAccountPublicKey: PublicKey = SystemProgram.programId;
+    let rewardMintPublicKey: PublicKey = SystemProgram.programId;
+
+    if (tokenType === TokenType.SPL) {
+      const rewardPoolAccount: any = await program.account.rewardPool.fetch(rewardPoolPublicKey);
+      rewardMintPublicKey = rewardPoolAccount.mint as PublicKey;
+      rewardEscrowPublicKey = getRewardEscrowPDA(rewardPoolPublicKey);
+      userRewardTokenAccountPublicKey = getAssociatedTokenAddressSync(
+        rewardMintPublicKey,
+        userPublicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID
+      );
+    }
+
     const { blockhash } = await connection.getLatestBlockhash('finalized');
 
     console.log("Pool Escrow Account:", poolEscrowAccountPublicKey.toBase58());
 
     // Build instruction
     const instruction = await program.methods
-      .unstake()
+      .unstake(new anchor.BN(positionId), amountInBaseUnits)
       .accounts({
         user: userPublicKey,
         stakingPool: stakingPoolPublicKey,
+        platformConfig: getPlatformConfigPDA(),
+        stakeIndex: stakeIndexPublicKey,
         userStakingAccount: userStakingAccountPublicKey,
+        rewardPool: rewardPoolPublicKey,
+        rewardEscrowAccount: rewardEscrowPublicKey,
+        userRewardTokenAccount: userRewardTokenAccountPublicKey,
+        rewardMint: rewardMintPublicKey,
         userTokenAccount: userTokenAccountPublicKey,
         poolEscrowAccount: poolEscrowAccountPublicKey,
         mint: actualMint,
@@ -300,27 +358,30 @@ export const unstakeTokenService = async (
 };
 
 
-// Function to claim staking rewards
+// Function to claim staking rewards for a stake position
 export const claimRewardsService = async (
   userPublicKey: PublicKey,
   adminPublicKey: PublicKey,
-  tokenType: TokenType
+  tokenType: TokenType,
+  positionId: number
 ) => {
   try {
     const { program, connection } = getProgram();
 
     const stakingPoolPublicKey = getStakingPoolPDA(adminPublicKey, tokenType);
 
-    const userStakingAccountPublicKey = getUserStakingPDA(stakingPoolPublicKey, userPublicKey);
-
-    const rewardPoolPublicKey = getRewardPoolPDA(adminPublicKey, tokenType);
+    const stakeIndexPublicKey = getUserStakeIndexPDA(stakingPoolPublicKey, userPublicKey);
 
-    const rewardEscrowPublicKey = getRewardEscrowPDA(rewardPoolPublicKey);
+    const userStakingAccountPublicKey = getUserStakingPDA(stakingPoolPublicKey, userPublicKey, positionId);
 
-    // Fetch staking pool to obtain mint
+    // Fetch staking pool to obtain mint and linked reward pool
     const stakingPoolAccount: any = await program.account.stakingPool.fetch(stakingPoolPublicKey);
     const mintPublicKey: PublicKey = stakingPoolAccount.mint as PublicKey;
 
+    const rewardPoolPublicKey = stakingPoolAccount.rewardPool as PublicKey;
+
+    const rewardEscrowPublicKey = getRewardEscrowPDA(rewardPoolPublicKey);
+
     // Ensure the user has an ATA
     let userTokenAccountPublicKey = await getAssociatedTokenAddressSync(mintPublicKey, userPublicKey, false, TOKEN_2022_PROGRAM_ID);
     const accountInfo = await connection.getAccountInfo(userTokenAccountPublicKey);
@@ -335,10 +396,12 @@ export const claimRewardsService = async (
     const { blockhash } = await connection.getLatestBlockhash('finalized');
 
     const transaction = await program.methods
-      .claimRewards()
+      .claimRewards(new anchor.BN(positionId))
       .accounts({
         user: userPublicKey,
         stakingPool: stakingPoolPublicKey,
+        platformConfig: getPlatformConfigPDA(),
+        stakeIndex: stakeIndexPublicKey,
         userStakingAccount: userStakingAccountPublicKey,
         rewardPool: rewardPoolPublicKey,
         userTokenAccount: userTokenAccountPublicKey,
@@ -365,20 +428,21 @@ export const claimRewardsService = async (
 
 
 
-export const getUserStakingAccount = async (userPublicKey: PublicKey, adminPublicKey: PublicKey, tokenType: TokenType) => {
+export const getUserStakingAccount = async (userPublicKey: PublicKey, adminPublicKey: PublicKey, tokenType: TokenType, positionId: number, mintPublicKey?: PublicKey) => {
   try {
     const { program, connection } = getProgram();
 
 
-    const stakingPoolPublicKey = getStakingPoolPDA(adminPublicKey, tokenType);
+    const stakingPoolPublicKey = getStakingPoolPDA(adminPublicKey, tokenType, mintPublicKey);
 
     console.log("Token Type:", tokenType === TokenType.SPL ? "SPL" : "SOL");
     console.log("Staking Pool PublicKey:", stakingPoolPublicKey.toBase58());
     console.log("User PublicKey:", userPublicKey.toBase58());
     console.log("Admin PublicKey:", adminPublicKey.toBase58());
+    console.log("Position ID:", positionId);
 
-    // Derive the public key for the user staking account
-    const userStakingAccountPublicKey = getUserStakingPDA(stakingPoolPublicKey, userPublicKey);
+    // Derive the public key for the stake position
+    const userStakingAccountPublicKey = getUserStakingPDA(stakingPoolPublicKey, userPublicKey, positionId);
 
     console.log(userStakingAccountPublicKey);
 
@@ -386,7 +450,7 @@ export const getUserStakingAccount = async (userPublicKey: PublicKey, adminPubli
     const accountExists = await connection.getAccountInfo(userStakingAccountPublicKey);
 
     if (!accountExists) {
-      return { success: false, message: "User has not staked any tokens yet." };
+      return { success: false, message: "Stake position not found." };
     }
 
     // Fetch staking data
@@ -452,6 +516,7 @@ export const getUserStakingAccount = async (userPublicKey: PublicKey, adminPubli
     // Ensure that the fields are defined and use safe .toString() calls
     const rawData = {
       owner: userStakingAccount.owner.toBase58(),
+      positionId: userStakingAccount.positionId.toString(),
       stakedAmount: readableStakedAmount,
       stakedAmountRaw: userStakingAccount.stakedAmount.toString(), // Raw base units for debugging
       stakeTimestamp: userStakingAccount.stakeTimestamp.toString(),
@@ -459,6 +524,8 @@ export const getUserStakingAccount = async (userPublicKey: PublicKey, adminPubli
       weight: userStakingAccount.weight.toString(),
       rewardDebt: readableRewardDebt,
       pendingRewards: readablePendingRewards,
+      lockTier: userStakingAccount.lockTier,
+      multiplierBps: userStakingAccount.multiplierBps.toString(),
     };
 
     console.log("Converted User Staking Account Data:", rawData);
@@ -471,17 +538,57 @@ export const getUserStakingAccount = async (userPublicKey: PublicKey, adminPubli
   }
 };
 
+// Function to fetch every open stake position a user holds in a pool
+export const getUserStakePositions = async (userPublicKey: PublicKey, adminPublicKey: PublicKey, tokenType: TokenType) => {
+  try {
+    const { program } = getProgram();
+
+    const stakingPoolPublicKey = getStakingPoolPDA(adminPublicKey, tokenType);
+    const stakeIndexPublicKey = getUserStakeIndexPDA(stakingPoolPublicKey, userPublicKey);
+
+    const stakeIndex = await program.account.userStakeIndex.fetchNullable(stakeIndexPublicKey) as UserStakeIndexAccount | null;
+    if (!stakeIndex || stakeIndex.openPositions.length === 0) {
+      return { success: false, message: "User has not staked any tokens yet." };
+    }
+
+    const positions = [];
+    for (const positionId of stakeIndex.openPositions) {
+      const position = await getUserStakingAccount(userPublicKey, adminPublicKey, tokenType, positionId.toNumber());
+      if (position.success && position.data) {
+        positions.push(position.data);
+      }
+    }
+
+    const totalStakedRaw = positions
+      .reduce((total, position) => total.add(new anchor.BN(position.stakedAmountRaw)), new anchor.BN(0))
+      .toString();
+
+    return {
+      success: true,
+      data: {
+        owner: userPublicKey.toBase58(),
+        totalStakedRaw,
+        positions,
+      },
+    };
+  } catch (err) {
+    console.error("❌ Error fetching user stake positions:", err);
+    return { success: false, message: "Error fetching user stake positions." };
+  }
+};
+
 
 
 
 
 
 
-// Function to accrue pending rewards for a specific staker
+// Function to accrue pending rewards for a specific stake position
 export const accrueRewardsService = async (
   userPublicKey: PublicKey,
   adminPublicKey: PublicKey,
-  tokenType: TokenType
+  tokenType: TokenType,
+  positionId: number
 ) => {
   try {
     const { program, connection } = getProgram();
@@ -491,13 +598,13 @@ export const accrueRewardsService = async (
     // Get the staking pool PDA
     const stakingPoolPublicKey = getStakingPoolPDA(adminPublicKey, tokenType);
 
-    // Get the user staking account PDA
-    const userStakingPublicKey = getUserStakingPDA(stakingPoolPublicKey, userPublicKey);
+    // Get the stake position PDA
+    const userStakingPublicKey = getUserStakingPDA(stakingPoolPublicKey, userPublicKey, positionId);
 
     // Build an unsigned transaction for the user to sign
     const { blockhash } = await connection.getLatestBlockhash('finalized');
     const transaction = await program.methods
-      .accrueRewards()
+      .accrueRewards(new anchor.BN(positionId))
       .accounts({
         user: userPublicKey,
         stakingPool: stakingPoolPublicKey,
@@ -1,7 +1,7 @@
 //backend/src/staking/stakingController.ts
 
 import { Request, Response } from 'express';
-import { unstakeTokenService, getUserStakingAccount, stakeTokenService, getProgram, claimRewardsService, accrueRewardsService } from './services';
+import { unstakeTokenService, getUserStakingAccount, getUserStakePositions, stakeTokenService, getProgram, claimRewardsService, accrueRewardsService } from './services';
 import { PublicKey } from '@solana/web3.js';
 import {TokenType } from "../utils/getPDAs";
 
@@ -11,29 +11,29 @@ import {TokenType } from "../utils/getPDAs";
 export const stakeTokensController = async (req: Request, res: Response) => {
   console.log('Staking invoked');
   try {
-    const { mintPublicKey, userPublicKey, amount, lockDuration, adminPublicKey, tokenType } = req.body;
+    const { mintPublicKey, userPublicKey, amount, tierIndex, adminPublicKey, tokenType } = req.body;
 
     // Validate required fields
-    if (!mintPublicKey || !userPublicKey || !amount || !lockDuration || !adminPublicKey || tokenType === undefined || tokenType === null) {
+    if (!mintPublicKey || !userPublicKey || !amount || tierIndex === undefined || tierIndex === null || !adminPublicKey || tokenType === undefined || tokenType === null) {
       return res.status(400).json({
         success: false,
-        message: "Missing required fields: mintPublicKey, userPublicKey, amount, lockDuration, adminPublicKey, and tokenType are required"
+        message: "Missing required fields: mintPublicKey, userPublicKey, amount, tierIndex, adminPublicKey, and tokenType are required"
       });
     }
 
     // Validate types
-    if (typeof amount !== 'number' || typeof lockDuration !== 'number') {
+    if (typeof amount !== 'number' || typeof tierIndex !== 'number') {
       return res.status(400).json({
         success: false,
-        message: "Amount and lockDuration must be numbers"
+        message: "Amount and tierIndex must be numbers"
       });
     }
 
-    // Validate positive values
-    if (amount <= 0 || lockDuration <= 0) {
+    // Validate ranges (tierIndex selects a lock tier from the pool's registry)
+    if (amount <= 0 || !Number.isInteger(tierIndex) || tierIndex < 0) {
       return res.status(400).json({
         success: false,
-        message: "Amount and lockDuration must be positive numbers"
+        message: "Amount must be positive and tierIndex must be a non-negative integer"
       });
     }
 
@@ -41,7 +41,7 @@ export const stakeTokensController = async (req: Request, res: Response) => {
       mintPublicKey,
       userPublicKey,
       amount,
-      lockDuration,
+      tierIndex,
       adminPublicKey,
       tokenType
     });
@@ -68,7 +68,7 @@ export const stakeTokensController = async (req: Request, res: Response) => {
       new PublicKey(mintPublicKey),
       new PublicKey(userPublicKey),
       amount,
-      lockDuration,
+      tierIndex,
       new PublicKey(adminPublicKey),
       tt as TokenType
     );
@@ -90,13 +90,13 @@ export const stakeTokensController = async (req: Request, res: Response) => {
 // ✅ Controller to claim staking rewards
 export const claimRewardsController = async (req: Request, res: Response) => {
   try {
-    const { userPublicKey, adminPublicKey, tokenType } = req.body;
+    const { userPublicKey, adminPublicKey, tokenType, positionId } = req.body;
 
-    if (!userPublicKey || !adminPublicKey || (tokenType === undefined || tokenType === null)) {
-      return res.status(400).json({ success: false, message: 'userPublicKey, adminPublicKey, and tokenType are required' });
+    if (!userPublicKey || !adminPublicKey || (tokenType === undefined || tokenType === null) || positionId === undefined || positionId === null) {
+      return res.status(400).json({ success: false, message: 'userPublicKey, adminPublicKey, tokenType, and positionId are required' });
     }
 
-    const result = await claimRewardsService(new PublicKey(userPublicKey), new PublicKey(adminPublicKey), tokenType as unknown as TokenType);
+    const result = await claimRewardsService(new PublicKey(userPublicKey), new PublicKey(adminPublicKey), tokenType as unknown as TokenType, Number(positionId));
     if (result.success) {
       return res.status(200).json(result);
     } else {
@@ -110,13 +110,21 @@ export const claimRewardsController = async (req: Request, res: Response) => {
 
 export const unstakeTokensController = async (req: Request, res: Response) => {
   try {
-    const { userPublicKey, adminPublicKey, tokenType, mintPublicKey } = req.body;
+    const { userPublicKey, adminPublicKey, tokenType, mintPublicKey, positionId, amount } = req.body;
 
     // Validate required fields
-    if (!userPublicKey || !adminPublicKey || tokenType === undefined || tokenType === null || !mintPublicKey) {
+    if (!userPublicKey || !adminPublicKey || tokenType === undefined || tokenType === null || !mintPublicKey || positionId === undefined || positionId === null) {
       return res.status(400).json({
         success: false,
-        message: "Missing required fields: userPublicKey, adminPublicKey, tokenType and mintPublicKey are required"
+        message: "Missing required fields: userPublicKey, adminPublicKey, tokenType, mintPublicKey and positionId are required"
+      });
+    }
+
+    // amount is optional; when omitted the whole position is unstaked
+    if (amount !== undefined && (typeof amount !== 'number' || amount <= 0)) {
+      return res.status(400).json({
+        success: false,
+        message: "amount must be a positive number when provided"
       });
     }
 
@@ -137,7 +145,7 @@ export const unstakeTokensController = async (req: Request, res: Response) => {
       return res.status(400).json({ success: false, message: 'tokenType must be 0 (SPL) or 1 (SOL)' });
     }
 
-    const result = await unstakeTokenService(new PublicKey(mintPublicKey), new PublicKey(userPublicKey), new PublicKey(adminPublicKey), tt as TokenType);
+    const result = await unstakeTokenService(new PublicKey(mintPublicKey), new PublicKey(userPublicKey), new PublicKey(adminPublicKey), tt as TokenType, Number(positionId), amount);
 
     if (result.success) {
       return res.status(200).json(result);
@@ -156,7 +164,7 @@ export const unstakeTokensController = async (req: Request, res: Response) => {
 export const fetchUserStakingAccountController = async (req: Request, res: Response) => {
   try {
     const { userPublicKey } = req.params;
-    const { tokenType, adminPublicKey } = req.query;
+    const { tokenType, adminPublicKey, positionId } = req.query;
 
     if (!userPublicKey || tokenType === undefined || tokenType === null || !adminPublicKey) {
       return res.status(400).json({ success: false, message: "User public key is required" });
@@ -166,8 +174,11 @@ export const fetchUserStakingAccountController = async (req: Request, res: Respo
       return res.status(400).json({ success: false, message: 'tokenType must be 0 (SPL) or 1 (SOL)' });
     }
 
+    // Without a positionId, return every open position the user holds in the pool
     const userPubkey = new PublicKey(userPublicKey);
-    const result = await getUserStakingAccount(userPubkey, new PublicKey(adminPublicKey), tt as TokenType);
+    const result = positionId === undefined
+      ? await getUserStakePositions(userPubkey, new PublicKey(adminPublicKey), tt as TokenType)
+      : await getUserStakingAccount(userPubkey, new PublicKey(adminPublicKey), tt as TokenType, Number(positionId));
 
     if (result.success) {
       return res.status(200).json(result);
@@ -183,19 +194,19 @@ export const fetchUserStakingAccountController = async (req: Request, res: Respo
 // Controller function to accrue rewards for a specific user
 export const accrueRewardsController = async (req: Request, res: Response) => {
   try {
-    const { userPublicKey, adminPublicKey, tokenType } = req.body;
+    const { userPublicKey, adminPublicKey, tokenType, positionId } = req.body;
 
-    if (!userPublicKey || !adminPublicKey || (tokenType === undefined || tokenType === null)) {
+    if (!userPublicKey || !adminPublicKey || (tokenType === undefined || tokenType === null) || positionId === undefined || positionId === null) {
       return res.status(400).json({
         success: false,
-        message: "userPublicKey, adminPublicKey, and tokenType are required."
+        message: "userPublicKey, adminPublicKey, tokenType, and positionId are required."
       });
     }
 
     const userPubkey = new PublicKey(userPublicKey);
     const adminPubkey = new PublicKey(adminPublicKey);
 
-    const result = await accrueRewardsService(userPubkey, adminPubkey, tokenType as unknown as TokenType);
+    const result = await accrueRewardsService(userPubkey, adminPubkey, tokenType as unknown as TokenType, Number(positionId));
 
     if (result.success) {
       return res.status(200).json(result);
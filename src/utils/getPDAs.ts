@@ -1,4 +1,5 @@
 import { PublicKey } from "@solana/web3.js";
+import { BN } from "bn.js";
 import { getProgram } from "../staking/services";
 
 
@@ -11,11 +12,15 @@ export const SEEDS = {
   REWARD_POOL_ESCROW: "reward_escrow",
   TOURNAMENT_POOL: "tournament_pool",
   USER_STAKING: "user_staking",
+  USER_STAKE_INDEX: "user_stake_index",
   REGISTRATION: "registration",
   TOURNAMENT_ESCROW: "escrow", // Tournament pool also uses "escrow" for its escrow
   SOL_VAULT: "sol_vault",
   PLATFORM_CONFIG: "platform_config",
   DEVELOPER_ONBOARDING: "developer_onboarding",
+  ROLE: "role",
+  LOCK_TIERS: "lock_tiers",
+  POOL_REGISTRY: "pool_registry",
 }
 
 export enum TokenType {
@@ -25,21 +30,37 @@ export enum TokenType {
 
 // TokenType is strictly numeric (0=SPL, 1=SOL). Clients must send 0 or 1.
 
+/**
+ * Get the mint component of staking and reward pool seeds
+ * @param tokenType - Type of token (SPL or SOL)
+ * @param mintPublicKey - Pool mint; defaults to TOKEN_MINT_ADDRESS for SPL
+ * @returns PublicKey.default for SOL pools, the pool mint for SPL pools
+ */
+export const getPoolSeedMint = (tokenType: TokenType, mintPublicKey?: PublicKey) => {
+  if (tokenType === TokenType.SOL) {
+    return PublicKey.default;
+  }
+  return mintPublicKey ?? new PublicKey(process.env.TOKEN_MINT_ADDRESS as string);
+};
+
 /**
  * Get Staking Pool PDA
  * @param adminPublicKey - Admin who initialized the pool
  * @param tokenType - Type of token (SPL or SOL)
+ * @param mintPublicKey - Staked mint (ignored for SOL)
  * @returns Staking Pool PDA
  */
 export const getStakingPoolPDA = (
   adminPublicKey: PublicKey,
   tokenType: TokenType,
+  mintPublicKey?: PublicKey,
 ) => {
   const { program } = getProgram();
   return PublicKey.findProgramAddressSync(
     [
       Buffer.from(SEEDS.STAKING_POOL), 
       adminPublicKey.toBuffer(),
+      getPoolSeedMint(tokenType, mintPublicKey).toBuffer(),
       Buffer.from([tokenType]) // ✅ CRITICAL: Include token type
     ],
     program.programId
@@ -76,12 +97,13 @@ export const getStakingEscrowPDA = (stakingPoolPublicKey: PublicKey) => {
  * Get Reward Pool PDA
  * @param adminPublicKey - Admin who initialized the pool
  * @param tokenType - Type of token (SPL or SOL)
+ * @param mintPublicKey - Reward mint (ignored for SOL)
  * @returns Reward Pool PDA
  */
-export const getRewardPoolPDA = (adminPublicKey: PublicKey, tokenType: TokenType) => {
+export const getRewardPoolPDA = (adminPublicKey: PublicKey, tokenType: TokenType, mintPublicKey?: PublicKey) => {
   const { program } = getProgram();
   return PublicKey.findProgramAddressSync(
-    [Buffer.from(SEEDS.REWARD_POOL), adminPublicKey.toBuffer(), Buffer.from([tokenType])],
+    [Buffer.from(SEEDS.REWARD_POOL), adminPublicKey.toBuffer(), getPoolSeedMint(tokenType, mintPublicKey).toBuffer(), Buffer.from([tokenType])],
     program.programId
   )[0];
 };
@@ -160,18 +182,55 @@ export const getPrizeEscrowPDA = (prizePoolPublicKey: PublicKey) => {
 };
 
 /**
- * Get User Staking Account PDA
+ * Get User Staking Account (stake position) PDA
  * @param stakingPoolPublicKey - The staking pool PDA (already includes tokenType)
  * @param userPublicKey - The user's public key
+ * @param positionId - The position id assigned by the user's stake index
  * @returns User Staking Account PDA
  */
 export const getUserStakingPDA = (
+  stakingPoolPublicKey: PublicKey,
+  userPublicKey: PublicKey,
+  positionId: number | BN
+) => {
+  const { program } = getProgram();
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(SEEDS.USER_STAKING),
+      stakingPoolPublicKey.toBuffer(),
+      userPublicKey.toBuffer(),
+      new BN(positionId).toArrayLike(Buffer, "le", 8)
+    ],
+    program.programId
+  )[0];
+};
+
+/**
+ * Get User Stake Index PDA
+ * @param stakingPoolPublicKey - The staking pool PDA
+ * @param userPublicKey - The user's public key
+ * @returns User Stake Index PDA (tracks the user's open position ids)
+ */
+export const getUserStakeIndexPDA = (
   stakingPoolPublicKey: PublicKey,
   userPublicKey: PublicKey
 ) => {
   const { program } = getProgram();
   return PublicKey.findProgramAddressSync(
-    [Buffer.from(SEEDS.USER_STAKING), stakingPoolPublicKey.toBuffer(), userPublicKey.toBuffer()],
+    [Buffer.from(SEEDS.USER_STAKE_INDEX), stakingPoolPublicKey.toBuffer(), userPublicKey.toBuffer()],
+    program.programId
+  )[0];
+};
+
+/**
+ * Get Lock Tier Registry PDA
+ * @param stakingPoolPublicKey - The staking pool PDA
+ * @returns Lock Tier Registry PDA for the staking pool
+ */
+export const getLockTiersPDA = (stakingPoolPublicKey: PublicKey) => {
+  const { program } = getProgram();
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(SEEDS.LOCK_TIERS), stakingPoolPublicKey.toBuffer()],
     program.programId
   )[0];
 };
@@ -203,6 +262,18 @@ export const getPlatformConfigPDA = () => {
   )[0];
 };
 
+/**
+ * Get Pool Registry PDA
+ * @returns Pool Registry PDA
+ */
+export const getPoolRegistryPDA = () => {
+  const { program } = getProgram();
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(SEEDS.POOL_REGISTRY)],
+    program.programId
+  )[0];
+};
+
 /**
  * Get Developer Onboarding Record PDA
  * @param developerPublicKey - The developer's public key
@@ -219,12 +290,39 @@ export const getDeveloperOnboardingRecordPDA = (developerPublicKey: PublicKey) =
   )[0];
 };
 
+/**
+ * Get Role Account PDA
+ * @param holderPublicKey - The key the roles are granted to
+ * @returns Role Account PDA
+ */
+export const getRoleAccountPDA = (holderPublicKey: PublicKey) => {
+  const { program } = getProgram();
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(SEEDS.ROLE), holderPublicKey.toBuffer()],
+    program.programId
+  )[0];
+};
+
+/**
+ * Resolve the optional role account for an admin instruction
+ * The super admin holds every role and needs no role account, so when the
+ * authority has no RoleAccount the program ID is passed to mean "none"
+ * @param authorityPublicKey - The signing authority
+ * @returns Role Account PDA, or the program ID when none exists
+ */
+export const resolveRoleAccount = async (authorityPublicKey: PublicKey) => {
+  const { program, connection } = getProgram();
+  const roleAccountPDA = getRoleAccountPDA(authorityPublicKey);
+  const accountInfo = await connection.getAccountInfo(roleAccountPDA);
+  return accountInfo ? roleAccountPDA : program.programId;
+};
+
 /**
  * Get all pool PDAs at once (convenience function)
  * @param adminPublicKey - Admin public key (optional)
  * @param opts - Optional parameters
  * @param opts.tournamentId - Tournament ID to derive tournament-related PDAs
- * @param opts.userPublicKey - User public key to derive user staking PDA
+ * @param opts.userPublicKey - User public key to derive the user stake index PDA
  * @param opts.tokenType - Token type for staking pool (defaults to SPL)
  * @returns Object containing all relevant PDAs
  */
@@ -266,10 +364,10 @@ export const getAllPoolPDAs = (
     }
   }
 
-  // User staking PDA (if user provided)
-  let userStaking: PublicKey | undefined;
+  // User stake index PDA (if user provided)
+  let userStakeIndex: PublicKey | undefined;
   if (opts?.userPublicKey) {
-    userStaking = getUserStakingPDA(stakingPool, opts.userPublicKey);
+    userStakeIndex = getUserStakeIndexPDA(stakingPool, opts.userPublicKey);
   }
 
   return {
@@ -281,7 +379,7 @@ export const getAllPoolPDAs = (
     tournamentEscrow, // ✅ Added
     prizePool,
     prizeEscrow,
-    userStaking,
+    userStakeIndex,
     registration, // ✅ Added
   };
 };
@@ -302,27 +400,27 @@ export const getAllStakingPoolPDAs = (
   // SPL Pool PDAs
   const splStakingPool = getStakingPoolPDA(admin, TokenType.SPL);
   const splStakingEscrow = getStakingEscrowPDA(splStakingPool);
-  const splUserStaking = opts?.userPublicKey 
-    ? getUserStakingPDA(splStakingPool, opts.userPublicKey)
+  const splUserStakeIndex = opts?.userPublicKey 
+    ? getUserStakeIndexPDA(splStakingPool, opts.userPublicKey)
     : undefined;
 
   // SOL Pool PDAs
   const solStakingPool = getStakingPoolPDA(admin, TokenType.SOL);
   const solStakingEscrow = getStakingEscrowPDA(solStakingPool);
-  const solUserStaking = opts?.userPublicKey
-    ? getUserStakingPDA(solStakingPool, opts.userPublicKey)
+  const solUserStakeIndex = opts?.userPublicKey
+    ? getUserStakeIndexPDA(solStakingPool, opts.userPublicKey)
     : undefined;
 
   return {
     spl: {
       stakingPool: splStakingPool,
       stakingEscrow: splStakingEscrow,
-      userStaking: splUserStaking,
+      userStakeIndex: splUserStakeIndex,
     },
     sol: {
       stakingPool: solStakingPool,
       stakingEscrow: solStakingEscrow,
-      userStaking: solUserStaking,
+      userStakeIndex: solUserStakeIndex,
     },
   };
 };
@@ -358,8 +456,8 @@ export const logAllPDAs = (
     console.log("Registration:", pdas.registration?.toBase58());
   }
 
-  if (pdas.userStaking) {
-    console.log("User Staking:", pdas.userStaking.toBase58());
+  if (pdas.userStakeIndex) {
+    console.log("User Stake Index:", pdas.userStakeIndex.toBase58());
   }
 
   console.log("================");